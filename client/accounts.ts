@@ -35,6 +35,34 @@ export function derivePostGroupAddress(random_seed: Uint8Array) {
   );
 }
 
+/**
+ * Derive a channel's PDA address, given the `random_seed` from `deriveChannelSeed`
+ */
+export function deriveChannelAddress(random_seed: Uint8Array) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("channel", "utf8"),
+      random_seed,
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive the random seed of a channel within a parent PostGroup
+ *
+ * note: the `channelId` is the parent's `channelCount` when the channel was created
+ */
+export async function deriveChannelSeed(parentGroup: PublicKey, channelId: number) {
+  const seed = await anchor.web3.PublicKey.createWithSeed(
+    parentGroup,
+    channelId.toString(),
+    anchor.workspace.Social.programId,
+  );
+  return seed.toBytes();
+}
+
 /**
  * Derive a Post's PDA address
  */
@@ -50,6 +78,320 @@ export function derivePostAddress(group: PublicKey, post_id: number) {
   );
 }
 
+/**
+ * Derive a Conversation's PDA address from its two participating profiles
+ *
+ * note: the profiles may be provided in any order
+ */
+export function deriveConversationAddress(profileA: PublicKey, profileB: PublicKey) {
+  // the program always uses the sorted profile addresses as the seeds
+  const [first, second] = [profileA.toBuffer(), profileB.toBuffer()].sort(Buffer.compare);
+
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("conversation", "utf8"),
+      first,
+      second,
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Message's PDA address
+ */
+export function deriveMessageAddress(conversation: PublicKey, message_id: number) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("message", "utf8"),
+      conversation.toBytes(),
+      Buffer.from(message_id.toString(), "utf-8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Tag's PDA address from its normalized name (e.g. `solana` for `#Solana`)
+ */
+export function deriveTagAddress(name: string) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("tag", "utf8"),
+      Buffer.from(name, "utf8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a TagEntry's PDA address
+ */
+export function deriveTagEntryAddress(tag: PublicKey, entry_id: number) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("tag_entry", "utf8"),
+      tag.toBytes(),
+      Buffer.from(entry_id.toString(), "utf-8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Vouch's PDA address
+ *
+ * note: use a `skill` of `0` for a general endorsement
+ */
+export function deriveVouchAddress(endorser: PublicKey, endorsee: PublicKey, skill: number) {
+  const skillSeed = Buffer.alloc(2);
+  skillSeed.writeUInt16LE(skill);
+
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("vouch", "utf8"),
+      endorser.toBytes(),
+      endorsee.toBytes(),
+      skillSeed,
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive the Endorsement PDA address tracking the vouches between two profiles
+ */
+export function deriveEndorsementAddress(endorser: PublicKey, endorsee: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("endorsement", "utf8"),
+      endorser.toBytes(),
+      endorsee.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Reaction's PDA address
+ */
+export function deriveReactionAddress(post: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("reaction", "utf8"),
+      post.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Report's PDA address for a Post
+ */
+export function deriveReportAddress(post: PublicKey, reportId: number) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("report", "utf8"),
+      post.toBytes(),
+      Buffer.from(reportId.toString(), "utf-8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive an Engagement's PDA address, tracking the reputation `from` has given `to`
+ */
+export function deriveEngagementAddress(from: PublicKey, to: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("engagement", "utf8"),
+      from.toBytes(),
+      to.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Membership's PDA address for a profile within a PostGroup
+ */
+export function deriveMembershipAddress(group: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("membership", "utf8"),
+      group.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a GroupRole's PDA address for a profile within a PostGroup
+ */
+export function deriveGroupRoleAddress(group: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("group_role", "utf8"),
+      group.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Permission bitflags that can be granted via a GroupRole
+ */
+export const GroupPermission = {
+  MANAGE_MEMBERS: 1 << 0,
+  HIDE_POSTS: 1 << 1,
+  LOCK_THREADS: 1 << 2,
+  PIN: 1 << 3,
+  EDIT_METADATA: 1 << 4,
+  ASSIGN_ROLES: 1 << 5,
+  MANAGE_CHANNELS: 1 << 6,
+  RESOLVE_REPORTS: 1 << 7,
+  LABEL_POSTS: 1 << 8,
+};
+
+/**
+ * Content labels that can be set on a Post (see `Post::NSFW`, etc)
+ */
+export const PostLabel = {
+  NSFW: 1 << 0,
+  SPOILER: 1 << 1,
+  GRAPHIC: 1 << 2,
+  POLITICAL: 1 << 3,
+  AI_GENERATED: 1 << 4,
+};
+
+/**
+ * Derive a PostActivity's PDA address for a profile within a PostGroup
+ */
+export function derivePostActivityAddress(group: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("post_activity", "utf8"),
+      group.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a PostGroup's GroupTreasury PDA address, which collects its slashed bonds
+ */
+export function deriveGroupTreasuryAddress(group: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("group_treasury", "utf8"),
+      group.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a Bond's PDA address for a profile within a PostGroup
+ */
+export function deriveBondAddress(group: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("bond", "utf8"),
+      group.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a GroupBan's PDA address for a profile within a PostGroup
+ */
+export function deriveGroupBanAddress(group: PublicKey, profile: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("group_ban", "utf8"),
+      group.toBytes(),
+      profile.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive the program's global Config PDA address
+ */
+export function deriveConfigAddress() {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("config", "utf8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive the program's Treasury PDA address, which collects the protocol fees
+ */
+export function deriveTreasuryAddress() {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("treasury", "utf8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a FeeExemption's PDA address for a payer
+ */
+export function deriveFeeExemptionAddress(payer: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("fee_exemption", "utf8"),
+      payer.toBytes(),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
+/**
+ * Derive a UsernameReservation's PDA address
+ */
+export function deriveUsernameReservationAddress(username: string) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("username_reservation", "utf8"),
+      Buffer.from(username, "utf8"),
+    ],
+    anchor.workspace.Social.programId,
+  );
+}
+
 type NameSpaceValue = "profile" | "post" | "post_group";
 
 /**
@@ -0,0 +1,129 @@
+/**
+ * Minimal Metaplex NFT helpers used to set up collection gated post groups
+ *
+ * note: the token metadata program must be loaded into the test validator (see `Anchor.toml`)
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import type { Keypair, PublicKey } from "@solana/web3.js";
+import { createAccount, createMint, mintTo } from "@solana/spl-token";
+import {
+  PROGRAM_ID as TOKEN_METADATA_PROGRAM_ID,
+  createCreateMasterEditionV3Instruction,
+  createCreateMetadataAccountV3Instruction,
+  createVerifyCollectionInstruction,
+} from "@metaplex-foundation/mpl-token-metadata";
+
+/**
+ * Derive an NFT's metadata account address
+ */
+export function deriveMetadataAddress(mint: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("metadata", "utf8"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+    ],
+    TOKEN_METADATA_PROGRAM_ID,
+  );
+}
+
+/**
+ * Derive an NFT's master edition account address
+ */
+export function deriveMasterEditionAddress(mint: PublicKey) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      // comment for better diffs
+      Buffer.from("metadata", "utf8"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+      Buffer.from("edition", "utf8"),
+    ],
+    TOKEN_METADATA_PROGRAM_ID,
+  );
+}
+
+/**
+ * Mint a new NFT to the `owner`, using the `payer` as its update authority.
+ * the NFT is only part of the (unverified) `collection` when one is provided
+ */
+export async function createNft(
+  provider: anchor.AnchorProvider,
+  payer: Keypair,
+  owner: PublicKey,
+  collection: PublicKey | null = null,
+) {
+  const mint = await createMint(provider.connection, payer, payer.publicKey, payer.publicKey, 0);
+  const tokenAccount = await createAccount(provider.connection, payer, mint, owner);
+  await mintTo(provider.connection, payer, mint, tokenAccount, payer, 1);
+
+  const [metadata] = deriveMetadataAddress(mint);
+  const [edition] = deriveMasterEditionAddress(mint);
+
+  const tx = new anchor.web3.Transaction().add(
+    createCreateMetadataAccountV3Instruction(
+      {
+        metadata,
+        mint,
+        mintAuthority: payer.publicKey,
+        payer: payer.publicKey,
+        updateAuthority: payer.publicKey,
+      },
+      {
+        createMetadataAccountArgsV3: {
+          data: {
+            name: "test nft",
+            symbol: "",
+            uri: "",
+            sellerFeeBasisPoints: 0,
+            creators: null,
+            collection: collection ? { key: collection, verified: false } : null,
+            uses: null,
+          },
+          isMutable: true,
+          collectionDetails: null,
+        },
+      },
+    ),
+    createCreateMasterEditionV3Instruction(
+      {
+        edition,
+        mint,
+        updateAuthority: payer.publicKey,
+        mintAuthority: payer.publicKey,
+        payer: payer.publicKey,
+        metadata,
+      },
+      { createMasterEditionArgs: { maxSupply: 0 } },
+    ),
+  );
+  await provider.sendAndConfirm(tx, [payer]);
+
+  return { mint, tokenAccount, metadata };
+}
+
+/**
+ * Verify the NFT of the `mint` is part of the `collection`
+ *
+ * note: the `payer` must be the collection's update authority (see `createNft`)
+ */
+export async function verifyCollection(
+  provider: anchor.AnchorProvider,
+  payer: Keypair,
+  mint: PublicKey,
+  collection: PublicKey,
+) {
+  const tx = new anchor.web3.Transaction().add(
+    createVerifyCollectionInstruction({
+      metadata: deriveMetadataAddress(mint)[0],
+      collectionAuthority: payer.publicKey,
+      payer: payer.publicKey,
+      collectionMint: collection,
+      collection: deriveMetadataAddress(collection)[0],
+      collectionMasterEditionAccount: deriveMasterEditionAddress(collection)[0],
+    }),
+  );
+  await provider.sendAndConfirm(tx, [payer]);
+}
@@ -0,0 +1,48 @@
+/**
+ * Helpers for claiming reserved usernames
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import type { Keypair, PublicKey } from "@solana/web3.js";
+import { deriveConfigAddress } from "./accounts";
+
+/**
+ * Build the message a reservation key signs to let the `claimant` claim a reserved `username`
+ * until the `expiresSlot` (inclusive)
+ *
+ * note: the `claimant` is the authority of the profile claiming the username. the message is
+ * bound to the program and its global `config` address, so vouchers can not be replayed
+ * against another deployment that uses the same reservation key
+ */
+export function buildVoucherMessage(
+  claimant: PublicKey,
+  expiresSlot: number,
+  username: string,
+  config: PublicKey = deriveConfigAddress()[0],
+) {
+  return Buffer.concat([
+    Buffer.from("claim_username", "utf8"),
+    anchor.workspace.Social.programId.toBuffer(),
+    config.toBuffer(),
+    claimant.toBuffer(),
+    new anchor.BN(expiresSlot).toArrayLike(Buffer, "le", 8),
+    Buffer.from(username, "utf8"),
+  ]);
+}
+
+/**
+ * Build the ed25519 signature verification instruction that must precede the
+ * `create_profile` or `change_username` instruction claiming a reserved `username`
+ */
+export function buildVoucherInstruction(
+  reservationKey: Keypair,
+  claimant: PublicKey,
+  expiresSlot: number,
+  username: string,
+  config: PublicKey = deriveConfigAddress()[0],
+) {
+  return anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: reservationKey.secretKey,
+    message: buildVoucherMessage(claimant, expiresSlot, username, config),
+  });
+}
@@ -16,4 +16,175 @@ pub enum GenericError {
 
     #[msg("The provided uri is invalid")]
     InvalidUri,
+
+    #[msg("Arithmetic overflow")]
+    Overflow,
+
+    #[msg("The provided message is empty or invalid")]
+    InvalidMessage,
+
+    #[msg("The provided message is too long")]
+    MessageTooLong,
+
+    #[msg("A conversation requires two different profiles")]
+    InvalidConversation,
+
+    #[msg("The provided tag is invalid")]
+    InvalidTag,
+
+    #[msg("Too many tags were provided")]
+    TooManyTags,
+
+    #[msg("The same tag was provided more than once")]
+    DuplicateTag,
+
+    #[msg("Too many co-authors were provided")]
+    TooManyCoAuthors,
+
+    #[msg("The same author was provided more than once")]
+    DuplicateCoAuthor,
+
+    #[msg("A profile cannot vouch for itself")]
+    InvalidVouch,
+
+    #[msg("The engagement account is required when engaging with another profile")]
+    MissingEngagementAccount,
+
+    #[msg("The profile does not have enough reputation")]
+    InsufficientReputation,
+
+    #[msg("The profile is not a member of the group")]
+    NotAMember,
+
+    #[msg("The group does not accept membership requests")]
+    MembershipClosed,
+
+    #[msg("The membership is not pending approval")]
+    MembershipNotPending,
+
+    #[msg("The provided permissions are invalid")]
+    InvalidPermissions,
+
+    #[msg("The profile is banned from the group")]
+    Banned,
+
+    #[msg("The group requires a token account to post")]
+    MissingTokenAccount,
+
+    #[msg("The token account does not hold enough of the required token")]
+    InsufficientTokenBalance,
+
+    #[msg("The group requires an NFT metadata account to post")]
+    MissingMetadataAccount,
+
+    #[msg("The NFT is not part of the group's verified collection")]
+    NotInCollection,
+
+    #[msg("The provided description is too long")]
+    DescriptionTooLong,
+
+    #[msg("Channels can not have their own channels")]
+    InvalidChannel,
+
+    #[msg("The group is archived")]
+    GroupArchived,
+
+    #[msg("The group must have no posts or channels, or be archived, to be closed")]
+    GroupNotEmpty,
+
+    #[msg("The report has already been resolved")]
+    ReportAlreadyResolved,
+
+    #[msg("The provided report outcome is invalid")]
+    InvalidReportOutcome,
+
+    #[msg("The provided content labels are invalid")]
+    InvalidLabels,
+
+    #[msg("The group requires a post activity account to post")]
+    MissingPostActivityAccount,
+
+    #[msg("The profile is posting too frequently")]
+    RateLimited,
+
+    #[msg("The group does not require a posting bond")]
+    InvalidBond,
+
+    #[msg("The group requires a posting bond to post")]
+    MissingBond,
+
+    #[msg("The posting bond is less than the group requires")]
+    InsufficientBond,
+
+    #[msg("The posting bond is being withdrawn")]
+    BondWithdrawalPending,
+
+    #[msg("The posting bond can not be withdrawn yet")]
+    BondCooldown,
+
+    #[msg("The program is paused")]
+    ProgramPaused,
+
+    #[msg("The provided config is invalid")]
+    InvalidConfig,
+
+    #[msg("The treasury does not hold enough lamports")]
+    InsufficientTreasuryBalance,
+
+    #[msg("The username is not leased")]
+    UsernameNotLeased,
+
+    #[msg("The username's lease and grace period have not ended")]
+    UsernameNotExpired,
+
+    #[msg("A claim voucher is required to register a reserved username")]
+    MissingVoucher,
+
+    #[msg("The claim voucher is invalid")]
+    InvalidVoucher,
+
+    #[msg("The post has not been removed by a moderator")]
+    PostNotRemoved,
+
+    #[msg("The bond has already been slashed for this post")]
+    BondAlreadySlashed,
+
+    #[msg("The treasury account is required to pay the protocol fee")]
+    MissingTreasuryAccount,
+
+    #[msg("The username can not be empty")]
+    EmptyUsername,
+
+    #[msg("The parent group is required when the group is a channel")]
+    MissingParentGroup,
+
+    #[msg("The channel inherits this from its parent group")]
+    InheritedFromParent,
+
+    #[msg("The claim voucher has expired")]
+    VoucherExpired,
+
+    #[msg("The rate limit must allow at least one post and refill over time")]
+    InvalidRateLimit,
+
+    #[msg("The posting bond amount must be greater than zero")]
+    InvalidBondAmount,
+
+    #[msg("The token gate must require a non-zero amount of the token")]
+    InvalidTokenGate,
+
+    #[msg("The name can not be empty")]
+    EmptyName,
+
+    #[msg("The group was closed and can not be restored")]
+    GroupClosed,
+
+    #[msg("The parent group's ban account is required when the channel has its own memberships")]
+    MissingParentBan,
+
+    #[msg("The post is locked and no longer accepts replies")]
+    PostLocked,
+
+    #[msg("Username leases are disabled, so usernames can not be renewed")]
+    UsernameLeasesDisabled,
 }
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ModerationStatus;
+
+/// emitted when a group moderator changes the visibility of a Post
+#[event]
+pub struct PostModerated {
+    /// address of the moderated Post
+    pub post: Pubkey,
+
+    /// address of the PostGroup the post was published to
+    pub group: Pubkey,
+
+    /// profile of the group authority or moderator that moderated the post
+    pub moderator: Pubkey,
+
+    /// the post's new visibility
+    pub status: ModerationStatus,
+
+    /// code for the reason the post was moderated
+    pub reason: u8,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, LookupAccount, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct AcceptPostGroupTransfer<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// the profile accepting the transfer
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // ensure the post group is actually being transferred to this profile
+        constraint = group.pending_authority == Some(profile.key()) @ GenericError::Unauthorized,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    #[account(
+        mut,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            PostGroup::PREFIX_SEED.as_ref(),
+            group.name.as_ref()
+        ],
+        bump = lookup_account.bump,
+        // ensure the lookup account actually points to this group
+        constraint = lookup_account.address.key() == group.key() @ GenericError::InvalidAccount,
+    )]
+    pub lookup_account: Account<'info, LookupAccount>,
+}
+
+/// Accept a pending PostGroup transfer (step 2 of 2)
+pub fn process_accept_post_group_transfer(ctx: Context<AcceptPostGroupTransfer>) -> Result<()> {
+    let new_authority = ctx.accounts.profile.key();
+
+    // hand over the group and its lookup account to the new profile.
+    // all of the group's posts are left untouched
+    ctx.accounts.group.authority = new_authority;
+    ctx.accounts.group.pending_authority = None;
+    ctx.accounts.lookup_account.authority = new_authority;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, FeeExemption};
+
+#[derive(Accounts)]
+#[instruction(exempt_payer: Pubkey)]
+pub struct AddFeeExemption<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = FeeExemption::SPACE,
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            exempt_payer.as_ref(),
+        ],
+        bump,
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+}
+
+/// Allowlist a payer, exempting it from paying any protocol fees
+pub fn process_add_fee_exemption(ctx: Context<AddFeeExemption>, exempt_payer: Pubkey) -> Result<()> {
+    ctx.accounts.fee_exemption.set_inner(FeeExemption {
+        bump: ctx.bumps.fee_exemption,
+        payer: exempt_payer,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
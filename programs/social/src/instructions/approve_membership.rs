@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, Membership, MembershipStatus, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct ApproveMembership<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // channels inheriting their parent's memberships and bans must be managed via the parent
+        constraint = group.membership_group(group.key()) == group.key() @ GenericError::InheritedFromParent,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    #[account(
+        mut,
+        seeds = [
+            Membership::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            membership.profile.as_ref(),
+        ],
+        bump = membership.bump,
+        constraint = membership.status == MembershipStatus::Pending @ GenericError::MembershipNotPending,
+    )]
+    pub membership: Account<'info, Membership>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Approve a pending membership request for a PostGroup
+pub fn process_approve_membership(ctx: Context<ApproveMembership>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to manage the group's members
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::MANAGE_MEMBERS,
+    )?;
+
+    ctx.accounts.membership.status = MembershipStatus::Active;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
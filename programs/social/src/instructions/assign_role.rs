@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct AssignRole<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// the profile assigning the role
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // channels inheriting their parent's moderator roles must be managed via the parent
+        constraint = group.moderation_group(group.key()) == group.key() @ GenericError::InheritedFromParent,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the assigning profile's own role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+
+    /// the profile receiving the role
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            assignee.random_seed.as_ref()
+        ],
+        bump = assignee.bump,
+    )]
+    pub assignee: Account<'info, Profile>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = GroupRole::SPACE,
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            assignee.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub assignee_role: Account<'info, GroupRole>,
+}
+
+/// Assign (or change) the permissions of a Profile within a PostGroup
+pub fn process_assign_role(ctx: Context<AssignRole>, permissions: u16) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // the group's authority already has every permission
+    require_keys_neq!(
+        ctx.accounts.assignee.key(),
+        ctx.accounts.group.authority,
+        GenericError::InvalidAccount
+    );
+
+    require!(
+        permissions != 0 && permissions & !GroupRole::ALL == 0,
+        GenericError::InvalidPermissions
+    );
+
+    // ensure the profile is allowed to assign roles
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::ASSIGN_ROLES,
+    )?;
+
+    // moderators can never grant (or change) permissions they do not have themselves
+    if ctx.accounts.group.authority != ctx.accounts.profile.key() {
+        let granted = permissions | ctx.accounts.assignee_role.permissions;
+        require!(
+            ctx.accounts.role.as_ref().is_some_and(|role| role.has(granted)),
+            GenericError::Unauthorized
+        );
+    }
+
+    // actually store the provided data in the account
+    ctx.accounts.assignee_role.set_inner(GroupRole {
+        bump: ctx.bumps.assignee_role,
+        group: ctx.accounts.group.key(),
+        profile: ctx.accounts.assignee.key(),
+        permissions,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
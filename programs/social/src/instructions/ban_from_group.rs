@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupBan, GroupRole, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct BanFromGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// the group authority or moderator issuing the ban
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // channels inheriting their parent's memberships and bans must be managed via the parent
+        constraint = group.membership_group(group.key()) == group.key() @ GenericError::InheritedFromParent,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+
+    /// the profile being banned
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            banned_profile.random_seed.as_ref()
+        ],
+        bump = banned_profile.bump,
+    )]
+    pub banned_profile: Account<'info, Profile>,
+
+    /// note: banning an already banned profile updates the existing ban
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = GroupBan::SPACE,
+        seeds = [
+            GroupBan::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            banned_profile.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ban: Account<'info, GroupBan>,
+}
+
+/// Ban a Profile from posting (or replying) within a PostGroup, optionally until `expires_slot`
+pub fn process_ban_from_group(ctx: Context<BanFromGroup>, expires_slot: Option<u64>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // the group's authority can never be banned from its own group
+    require_keys_neq!(
+        ctx.accounts.banned_profile.key(),
+        ctx.accounts.group.authority,
+        GenericError::Unauthorized
+    );
+
+    // ensure the profile is allowed to manage the group's members
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::MANAGE_MEMBERS,
+    )?;
+
+    // actually store the provided data in the account
+    ctx.accounts.ban.set_inner(GroupBan {
+        bump: ctx.bumps.ban,
+        group: ctx.accounts.group.key(),
+        profile: ctx.accounts.banned_profile.key(),
+        banned_by: ctx.accounts.profile.key(),
+        expires_slot,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
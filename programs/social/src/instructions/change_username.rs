@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 
 use crate::errors::GenericError;
-use crate::state:: { Profile, LookupAccount } ;
+use crate::state::{Config, FeeExemption, LookupAccount, Profile, Treasury, UsernameReservation};
 
 #[derive(Accounts)]
 #[instruction(profile_seed: [u8;32], new_username: String)]
 pub struct ChangeUsername<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
     
     #[account(mut)]
@@ -40,6 +49,8 @@ pub struct ChangeUsername<'info> {
     )]
     pub new_lookup_account: Account<'info, LookupAccount>,
 
+    /// the lookup account of the profile's current username.
+    /// only required when the profile still has a username (see `release_username`)
     #[account(
         mut,
         // when closing the old lookup account, send the lamports to the new lookup account
@@ -55,12 +66,60 @@ pub struct ChangeUsername<'info> {
         // ensure the lookup account is owned by the profile's PDA
         constraint = old_lookup_account.authority.key() == profile.key() @ GenericError::Unauthorized,
     )]
-    pub old_lookup_account: Account<'info, LookupAccount>,
+    pub old_lookup_account: Option<Account<'info, LookupAccount>>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// CHECK: the username's (possibly uninitialized) UsernameReservation PDA.
+    /// this is always required so a reservation can not be bypassed by omitting it
+    #[account(
+        seeds = [
+            UsernameReservation::PREFIX_SEED.as_ref(),
+            new_username.as_ref()
+        ],
+        bump,
+    )]
+    pub reservation: UncheckedAccount<'info>,
+
+    /// CHECK: the instructions sysvar, used to verify the claim voucher.
+    /// only required when claiming a reserved username
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 }
 
 ///
 pub fn process_change_username(ctx: Context<ChangeUsername>, _profile_seed: [u8; 32], new_username: String) -> Result<()> {
     Profile::validate_username(&new_username)?;
+    // reserved usernames can only be claimed with a voucher from the reservation key
+    UsernameReservation::validate_claim(
+        &ctx.accounts.reservation,
+        &ctx.accounts.config,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &ctx.accounts.authority.key(),
+        &new_username,
+    )?;
+    require!(
+        ctx.accounts.old_lookup_account.is_some() != ctx.accounts.profile.username.is_empty(),
+        GenericError::InvalidAccount
+    );
 
     // store the new lookup account's data 
     ctx.accounts.new_lookup_account.set_inner(LookupAccount {
@@ -69,12 +128,26 @@ pub fn process_change_username(ctx: Context<ChangeUsername>, _profile_seed: [u8;
         address: ctx.accounts.profile.key(),
         // the profile PDA is set as the authority so that when the `profile.authority` changes, 
         // the same profile will still be able to update the inner data of this account
-        authority: ctx.accounts.profile.key()
+        authority: ctx.accounts.profile.key(),
+        // usernames are leased when the config enables leases
+        expires_at: ctx.accounts.config.username_lease_expiry(Clock::get()?.unix_timestamp),
     });
 
     // actually update the username
     ctx.accounts.profile.username = new_username;
 
+    // pay the protocol fee, plus the username's length based price
+    let fees = &ctx.accounts.config.fees;
+    let username_price = fees.username_price(&ctx.accounts.profile.username);
+    let fee = fees.change_username.saturating_add(username_price);
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        fee,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
     // emit an event for indexers to observe
     // todo
 
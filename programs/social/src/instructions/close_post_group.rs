@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, LookupAccount, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct ClosePostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership.
+    /// the reclaimed rent is also sent here
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// closed when it never had posts or channels, otherwise kept as an archived tombstone
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the group's lookup account, freeing up its name.
+    /// only required when the group is not a channel (which have no lookup account)
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            PostGroup::PREFIX_SEED.as_ref(),
+            group.name.as_ref()
+        ],
+        bump = lookup_account.bump,
+        // ensure the lookup account actually points to this group
+        constraint = lookup_account.address.key() == group.key() @ GenericError::InvalidAccount,
+    )]
+    pub lookup_account: Option<Account<'info, LookupAccount>>,
+}
+
+/// Close an empty or archived PostGroup and its LookupAccount, reclaiming their rent
+///
+/// note: only groups that never had posts or channels are actually closed. the account of a
+/// group with posts or channels must remain as a tombstone, since its posts and channels are
+/// derived from its address and would be reused if the group was created again. such a group
+/// must be archived first (see `set_post_group_archived`), and only releases its name
+pub fn process_close_post_group(ctx: Context<ClosePostGroup>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // only the group's authority may change this
+    require_keys_eq!(
+        ctx.accounts.group.authority,
+        ctx.accounts.profile.key(),
+        GenericError::Unauthorized
+    );
+
+    let group = &mut ctx.accounts.group;
+
+    // a root group's name must always be released along with it
+    require!(
+        group.parent_group.is_some() || ctx.accounts.lookup_account.is_some(),
+        GenericError::InvalidAccount
+    );
+
+    if group.post_count == 0 && group.channel_count == 0 {
+        group.close(ctx.accounts.authority.to_account_info())?;
+    } else {
+        // a channel has no name to release, so nothing would be reclaimed
+        require!(
+            group.archived && group.parent_group.is_none(),
+            GenericError::GroupNotEmpty
+        );
+
+        // the tombstone no longer owns its name, which may be claimed by another group
+        group.name = String::new();
+    }
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
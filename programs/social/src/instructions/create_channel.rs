@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, PostGroup, Profile};
+
+#[derive(Accounts)]
+#[instruction(input: PostGroup)]
+pub struct CreateChannel<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+        // channels are only nested a single level deep
+        constraint = parent_group.parent_group.is_none() @ GenericError::InvalidChannel,
+    )]
+    pub parent_group: Account<'info, PostGroup>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PostGroup::SPACE,
+        seeds = [
+            // channels use their own prefix, so a root group can never occupy a channel's address
+            PostGroup::CHANNEL_PREFIX_SEED.as_ref(),
+            // verified to be derived from the parent's current `channel_count`
+            input.random_seed.as_ref()
+        ],
+        bump,
+    )]
+    pub channel: Account<'info, PostGroup>,
+
+    /// the profile's role within the parent group.
+    /// only required when the profile is not the parent group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            parent_group.key().as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Create a channel (aka child PostGroup) within the `parent_group`
+///
+/// note: the channel's `random_seed` must be derived via `PostGroup::channel_seed`.
+/// the channel inherits its parent's memberships, bans, and moderators until overridden.
+/// channels are not registered in the PostGroup LookupAccount namespace,
+/// they are located by enumerating the parent's `channel_count` instead
+pub fn process_create_channel(ctx: Context<CreateChannel>, input: PostGroup) -> Result<()> {
+    // ensure the profile is allowed to manage the parent group's channels
+    GroupRole::authorize(
+        &ctx.accounts.parent_group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::MANAGE_CHANNELS,
+    )?;
+
+    // validate the input
+    PostGroup::validate_input(&input)?;
+
+    // the current `channel_count` is intentionally used here
+    let random_seed = PostGroup::channel_seed(
+        &ctx.accounts.parent_group.key(),
+        ctx.accounts.parent_group.channel_count,
+    )?;
+    require!(input.random_seed == random_seed, GenericError::InvalidChannel);
+
+    let parent_group = &mut ctx.accounts.parent_group;
+
+    // actually store the provided data in the account
+    ctx.accounts.channel.set_inner(PostGroup {
+        bump: ctx.bumps.channel,
+        random_seed,
+        name: input.name,
+        description: input.description,
+        image_uri: input.image_uri,
+        metadata_uri: input.metadata_uri,
+        content_hash: input.content_hash,
+        category: input.category,
+        post_count: 0,
+        archived: false,
+        // posting rules
+        min_reputation: input.min_reputation,
+        membership_mode: input.membership_mode,
+        rate_limit: input.rate_limit,
+        // the posting bond is only set via `set_post_group_bond`
+        posting_bond: None,
+        token_gate: input.token_gate,
+        collection_gate: input.collection_gate,
+        // the channel is owned by the same profile as its parent (refreshed via
+        // `PostGroup::resolve_authority` whenever the channel is used)
+        authority: parent_group.authority,
+        pending_authority: None,
+        parent_group: Some(parent_group.key()),
+        channel_count: 0,
+        // channels always start out inheriting from their parent,
+        // which can be overridden via `update_post_group`
+        inherit_membership: true,
+        inherit_moderation: true,
+    });
+
+    // auto increment the `parent_group.channel_count` for the next channel to use
+    parent_group.channel_count = parent_group
+        .channel_count
+        .checked_add(1)
+        .ok_or(GenericError::Overflow)?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
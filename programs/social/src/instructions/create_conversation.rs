@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Conversation, Profile};
+
+#[derive(Accounts)]
+pub struct CreateConversation<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `sender.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            sender.random_seed.as_ref()
+        ],
+        bump = sender.bump,
+
+        // ensure the sender's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub sender: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            recipient.random_seed.as_ref()
+        ],
+        bump = recipient.bump,
+        // a profile cannot start a conversation with itself
+        constraint = recipient.key() != sender.key() @ GenericError::InvalidConversation,
+    )]
+    pub recipient: Account<'info, Profile>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Conversation::SPACE,
+        seeds = [
+            Conversation::PREFIX_SEED.as_ref(),
+            // the sorted profile addresses ensure there is only one conversation per pair
+            Conversation::sort_profiles(sender.key(), recipient.key())[0].as_ref(),
+            Conversation::sort_profiles(sender.key(), recipient.key())[1].as_ref(),
+        ],
+        bump,
+    )]
+    pub conversation: Account<'info, Conversation>,
+}
+
+/// Create a direct message Conversation between the `sender` and `recipient` Profiles
+pub fn process_create_conversation(ctx: Context<CreateConversation>) -> Result<()> {
+    // actually store the provided data in the account
+    ctx.accounts.conversation.set_inner(Conversation {
+        bump: ctx.bumps.conversation,
+        profiles: Conversation::sort_profiles(
+            ctx.accounts.sender.key(),
+            ctx.accounts.recipient.key(),
+        ),
+        // no messages to start :)
+        message_count: 0,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
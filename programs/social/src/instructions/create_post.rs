@@ -1,11 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 
 use crate::errors::GenericError;
-use crate::state::{Post, PostGroup, Profile};
+use crate::state::{
+    Bond, Config, FeeExemption, GroupBan, Membership, ModerationStatus, Post, PostActivity,
+    PostEditRule, PostGroup, Profile, Treasury,
+};
+use crate::utils::{collect_co_authors, index_post_tags};
 
 #[derive(Accounts)]
 #[instruction(metadata_uri: String)]
 pub struct CreatePost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -43,23 +57,172 @@ pub struct CreatePost<'info> {
     #[account(
         mut,
         seeds = [
-            PostGroup::PREFIX_SEED.as_ref(),
+            group.prefix_seed(),
             group.random_seed.as_ref()
         ],
         bump,
-        // ensure the post group is owned by the author PDA
-        constraint = group.authority.key() == author.key() @ GenericError::Unauthorized,
     )]
     pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the author's membership within the group.
+    /// only required when the group is not `MembershipMode::Open`
+    #[account(
+        seeds = [
+            Membership::PREFIX_SEED.as_ref(),
+            // channels may inherit the memberships and bans of their parent
+            group.membership_group(group.key()).as_ref(),
+            author.key().as_ref(),
+        ],
+        bump = membership.bump,
+    )]
+    pub membership: Option<Account<'info, Membership>>,
+
+    /// CHECK: the author's (possibly uninitialized) GroupBan PDA within the group.
+    /// this is always required so a ban can not be bypassed by omitting it
+    #[account(
+        seeds = [
+            GroupBan::PREFIX_SEED.as_ref(),
+            // channels may inherit the memberships and bans of their parent
+            group.membership_group(group.key()).as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    /// CHECK: the author's (possibly uninitialized) GroupBan PDA within the group's parent.
+    /// only required when the group is a channel with its own memberships
+    #[account(
+        seeds = [
+            GroupBan::PREFIX_SEED.as_ref(),
+            group.parent_group.unwrap_or_default().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub parent_ban: Option<UncheckedAccount<'info>>,
+
+    /// the author's posting activity within the group.
+    /// only required when the group has a `rate_limit`
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PostActivity::SPACE,
+        seeds = [
+            PostActivity::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub activity: Option<Account<'info, PostActivity>>,
+
+    /// the author's posting bond within the group.
+    /// only required when the group has a `posting_bond`
+    #[account(
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+
+    /// the token account of the author's authority holding the group's gating token.
+    /// only required when the group has a `token_gate`
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// the token account of the author's authority holding an NFT from the group's
+    /// collection. only required when the group has a `collection_gate`
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// the metadata account of the NFT held in the `nft_token_account`
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 }
 
 /// Create a root Post that is published by the `author` (aka `Profile`)
-pub fn process_create_post(
-    ctx: Context<CreatePost>,
+///
+/// note: for each of the provided `tags`, the Tag and TagEntry PDAs must be
+/// provided via the `remaining_accounts` (see `index_post_tags`). any co-authors
+/// are provided via the rest of the `remaining_accounts` (see `collect_co_authors`)
+pub fn process_create_post<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatePost<'info>>,
     metadata_uri: String,
+    tags: Vec<String>,
+    edit_rule: PostEditRule,
+    labels: u8,
 ) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
     // validate the input
     Post::validate_uri(&metadata_uri)?;
+    Post::validate_labels(labels)?;
+
+    // enforce the group's posting rules
+    GroupBan::validate_not_banned(&ctx.accounts.ban)?;
+    GroupBan::validate_not_banned_from_parent(
+        &ctx.accounts.group,
+        ctx.accounts.parent_ban.as_ref(),
+    )?;
+    ctx.accounts.group.validate_author(&ctx.accounts.author)?;
+    ctx.accounts
+        .group
+        .validate_token_gate(&ctx.accounts.author, ctx.accounts.gate_token_account.as_ref())?;
+    ctx.accounts.group.validate_collection_gate(
+        &ctx.accounts.author,
+        ctx.accounts.nft_token_account.as_ref(),
+        ctx.accounts.nft_metadata.as_ref(),
+    )?;
+    Bond::validate(ctx.accounts.bond.as_ref(), &ctx.accounts.group, &ctx.accounts.author)?;
+    PostActivity::record(
+        ctx.accounts.activity.as_mut(),
+        ctx.bumps.activity,
+        &ctx.accounts.group,
+        &ctx.accounts.author,
+    )?;
+    ctx.accounts
+        .group
+        .validate_member(&ctx.accounts.author, ctx.accounts.membership.as_ref())?;
+
+    // the tag accounts are always provided before the co-author accounts
+    let (tag_accounts, co_author_accounts) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len().min(tags.len() * 2));
+    let co_authors = collect_co_authors(co_author_accounts, &ctx.accounts.author.key())?;
+    ctx.accounts.config.validate_post_limits(tags.len(), co_authors.len())?;
     
     // actually store the provided data in the account
     ctx.accounts.post.set_inner(Post {
@@ -70,9 +233,20 @@ pub fn process_create_post(
         // this works well because of the write lock feature of Solana :)
         post_id: ctx.accounts.group.post_count,
         author: ctx.accounts.author.key(),
+        co_authors,
+        edit_rule,
         metadata_uri,
         // no replies to start :)
         reply_count: 0,
+        report_count: 0,
+        labels,
+        // moderators can only label a post after it exists
+        moderator_labels: None,
+        moderation_status: ModerationStatus::Visible,
+        moderation_reason: 0,
+        bond_slashed: false,
+        locked: false,
+        pinned: false,
         // parent post is set to None when creating a "root post"
         parent_post: None,
     });
@@ -83,6 +257,24 @@ pub fn process_create_post(
     ctx.accounts.group.post_count += 1;
     // todo: safe math
 
+    // index the post under each of its tags for topic discovery
+    index_post_tags(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        tag_accounts,
+        ctx.accounts.post.key(),
+        &tags,
+    )?;
+
+    // pay the protocol fee, if any
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.accounts.config.fees.create_post,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
     // emit an event for indexers to observe
     // todo
 
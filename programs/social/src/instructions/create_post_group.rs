@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GenericError;
-use crate::state::{PostGroup, Profile, LookupAccount};
+use crate::state::{Config, FeeExemption, LookupAccount, PostGroup, Profile, Treasury};
 
 #[derive(Accounts)]
-#[instruction(random_seed: [u8; 32], name: String)]
+#[instruction(input: PostGroup)]
 pub struct CreatePostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -32,7 +40,7 @@ pub struct CreatePostGroup<'info> {
         space=PostGroup::SPACE,
         seeds = [
             PostGroup::PREFIX_SEED.as_ref(),
-            random_seed.as_ref()
+            input.random_seed.as_ref()
         ],
         bump,
     )]
@@ -45,21 +53,37 @@ pub struct CreatePostGroup<'info> {
         seeds = [
             LookupAccount::PREFIX_SEED.as_ref(),
             PostGroup::PREFIX_SEED.as_ref(),
-            name.as_ref()
+            input.name.as_ref()
         ],
         bump
     )]
-    pub lookup_account: Account<'info, LookupAccount>
+    pub lookup_account: Account<'info, LookupAccount>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 }
 
 /// Create a PostGroup that is published by the `author` (aka `Profile`)
-pub fn process_create_post_group(
-    ctx: Context<CreatePostGroup>,
-    random_seed: [u8;32],
-    name: String
-) -> Result<()> {
+pub fn process_create_post_group(ctx: Context<CreatePostGroup>, input: PostGroup) -> Result<()> {
     // validate the input
-    PostGroup::validate_name(&name)?;
+    PostGroup::validate_input(&input)?;
 
     // create the lookup account for the group being created
     ctx.accounts.lookup_account.set_inner(LookupAccount { 
@@ -69,19 +93,50 @@ pub fn process_create_post_group(
         // the author PDA is set as the authority so that when the `author.authority` changes, 
         // the same author will still be able to update the inner data of this account
         authority: ctx.accounts.author.key(),
+        // group names are never leased
+        expires_at: None,
     });
     
     // actually store the provided data in the account
     ctx.accounts.group.set_inner(PostGroup {
         bump: ctx.bumps.group,
-        random_seed: random_seed,
-        name: name,
+        random_seed: input.random_seed,
+        name: input.name,
+        description: input.description,
+        image_uri: input.image_uri,
+        metadata_uri: input.metadata_uri,
+        content_hash: input.content_hash,
+        category: input.category,
         post_count: 0,
+        archived: false,
+        // posting rules
+        min_reputation: input.min_reputation,
+        membership_mode: input.membership_mode,
+        rate_limit: input.rate_limit,
+        // the posting bond is only set via `set_post_group_bond`
+        posting_bond: None,
+        token_gate: input.token_gate,
+        collection_gate: input.collection_gate,
         // the author PDA is set as the authority so that when the `author.authority` changes, 
         // the same author will still be able to update the inner data of this account
         authority: ctx.accounts.author.key(),
+        pending_authority: None,
+        // root groups have no parent to inherit from
+        parent_group: None,
+        channel_count: 0,
+        inherit_membership: false,
+        inherit_moderation: false,
     });
 
+    // pay the protocol fee, if any
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.accounts.config.fees.create_post_group,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
     // emit an event for indexers to observe
     // todo
 
@@ -1,9 +1,20 @@
 use anchor_lang::prelude::*;
-use crate::state:: { Profile, LookupAccount };
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+
+use crate::errors::GenericError;
+use crate::state::{Config, FeeExemption, LookupAccount, Profile, Treasury, UsernameReservation};
 
 #[derive(Accounts)]
 #[instruction(input: Profile)]
 pub struct CreateProfile<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
     
     #[account(mut)]
@@ -36,11 +47,55 @@ pub struct CreateProfile<'info> {
         bump
     )]
     pub lookup_account: Account<'info, LookupAccount>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// CHECK: the username's (possibly uninitialized) UsernameReservation PDA.
+    /// this is always required so a reservation can not be bypassed by omitting it
+    #[account(
+        seeds = [
+            UsernameReservation::PREFIX_SEED.as_ref(),
+            input.username.as_ref()
+        ],
+        bump,
+    )]
+    pub reservation: UncheckedAccount<'info>,
+
+    /// CHECK: the instructions sysvar, used to verify the claim voucher.
+    /// only required when claiming a reserved username
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 }
 
 ///
 pub fn process_create_profile(ctx: Context<CreateProfile>, input: Profile) -> Result<()> {
     Profile::validate_input(&input)?;
+    // reserved usernames can only be claimed with a voucher from the reservation key
+    UsernameReservation::validate_claim(
+        &ctx.accounts.reservation,
+        &ctx.accounts.config,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &ctx.accounts.authority.key(),
+        &input.username,
+    )?;
     
     // store the new lookup account's data 
     ctx.accounts.lookup_account.set_inner(LookupAccount { 
@@ -49,7 +104,9 @@ pub fn process_create_profile(ctx: Context<CreateProfile>, input: Profile) -> Re
         address: ctx.accounts.profile.key(),
         // the profile PDA is set as the authority so that when the `profile.authority` changes, 
         // the same profile will still be able to update the inner data of this account
-        authority: ctx.accounts.profile.key()
+        authority: ctx.accounts.profile.key(),
+        // usernames are leased when the config enables leases
+        expires_at: ctx.accounts.config.username_lease_expiry(Clock::get()?.unix_timestamp),
     });
     
     // store the provided input data into the account
@@ -60,11 +117,27 @@ pub fn process_create_profile(ctx: Context<CreateProfile>, input: Profile) -> Re
         username: input.username,
         metadata_uri : input.metadata_uri,
         image_uri: input.image_uri,
+        messaging_key: input.messaging_key,
+        // no endorsements to start :)
+        endorsement_count: 0,
+        reputation: 0,
         // set the profile's authority to be the provided `authority` 
         // since it is already a signer on the transaction
         authority: ctx.accounts.authority.key(),
     });
 
+    // pay the protocol fee, plus the username's length based price
+    let fees = &ctx.accounts.config.fees;
+    let username_price = fees.username_price(&ctx.accounts.profile.username);
+    let fee = fees.create_profile.saturating_add(username_price);
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        fee,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
     // emit an event for indexers to observe
     // todo
 
@@ -1,11 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 
 use crate::errors::GenericError;
-use crate::state::{Post, Profile};
+use crate::state::{
+    Bond, Config, Engagement, FeeExemption, GroupBan, ModerationStatus, Post, PostActivity,
+    PostEditRule, PostGroup, Profile, Treasury,
+};
+use crate::utils::index_post_tags;
 
 #[derive(Accounts)]
 #[instruction(metadata_uri: String)]
 pub struct CreateReply<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -34,6 +48,8 @@ pub struct CreateReply<'info> {
             parent_post.post_id.to_string().as_bytes(),
         ],
         bump = parent_post.bump,
+        // moderators may lock a thread to stop any further replies
+        constraint = !parent_post.locked @ GenericError::PostLocked,
     )]
     pub parent_post: Account<'info, Post>,
 
@@ -51,12 +67,175 @@ pub struct CreateReply<'info> {
         bump,
     )]
     pub reply: Account<'info, Post>,
+
+    /// the group the parent post was published to, used to enforce its posting rules
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        address = parent_post.group @ GenericError::InvalidAccount,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the author of the parent post, which receives the reputation
+    #[account(
+        mut,
+        address = parent_post.author @ GenericError::InvalidAccount,
+    )]
+    pub parent_author: Account<'info, Profile>,
+
+    /// required unless the author is replying to its own post
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Engagement::SPACE,
+        seeds = [
+            Engagement::PREFIX_SEED.as_ref(),
+            author.key().as_ref(),
+            parent_author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub engagement: Option<Account<'info, Engagement>>,
+
+    /// CHECK: the author's (possibly uninitialized) GroupBan PDA within the group.
+    /// this is always required so a ban can not be bypassed by omitting it
+    #[account(
+        seeds = [
+            GroupBan::PREFIX_SEED.as_ref(),
+            // channels may inherit the memberships and bans of their parent
+            group.membership_group(group.key()).as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ban: UncheckedAccount<'info>,
+
+    /// CHECK: the author's (possibly uninitialized) GroupBan PDA within the group's parent.
+    /// only required when the group is a channel with its own memberships
+    #[account(
+        seeds = [
+            GroupBan::PREFIX_SEED.as_ref(),
+            group.parent_group.unwrap_or_default().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub parent_ban: Option<UncheckedAccount<'info>>,
+
+    /// the author's posting activity within the group.
+    /// only required when the group has a `rate_limit`
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PostActivity::SPACE,
+        seeds = [
+            PostActivity::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub activity: Option<Account<'info, PostActivity>>,
+
+    /// the author's posting bond within the group.
+    /// only required when the group has a `posting_bond`
+    #[account(
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            author.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Option<Account<'info, Bond>>,
+
+    /// the token account of the author's authority holding the group's gating token.
+    /// only required when the group has a `token_gate`
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// the token account of the author's authority holding an NFT from the group's
+    /// collection. only required when the group has a `collection_gate`
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// the metadata account of the NFT held in the `nft_token_account`
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
 }
 
 /// Create a reply Post to an existing Post
-pub fn process_create_reply(ctx: Context<CreateReply>, metadata_uri: String) -> Result<()> {
+///
+/// note: for each of the provided `tags`, the Tag and TagEntry PDAs must be
+/// provided via the `remaining_accounts` (see `index_post_tags`)
+pub fn process_create_reply<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateReply<'info>>,
+    metadata_uri: String,
+    tags: Vec<String>,
+    labels: u8,
+) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
     // validate the input
     Post::validate_uri(&metadata_uri)?;
+    Post::validate_labels(labels)?;
+    // replies never have co-authors
+    ctx.accounts.config.validate_post_limits(tags.len(), 0)?;
+
+    // enforce the group's posting rules
+    GroupBan::validate_not_banned(&ctx.accounts.ban)?;
+    GroupBan::validate_not_banned_from_parent(
+        &ctx.accounts.group,
+        ctx.accounts.parent_ban.as_ref(),
+    )?;
+    ctx.accounts.group.validate_author(&ctx.accounts.author)?;
+    ctx.accounts
+        .group
+        .validate_token_gate(&ctx.accounts.author, ctx.accounts.gate_token_account.as_ref())?;
+    ctx.accounts.group.validate_collection_gate(
+        &ctx.accounts.author,
+        ctx.accounts.nft_token_account.as_ref(),
+        ctx.accounts.nft_metadata.as_ref(),
+    )?;
+    Bond::validate(ctx.accounts.bond.as_ref(), &ctx.accounts.group, &ctx.accounts.author)?;
+    PostActivity::record(
+        ctx.accounts.activity.as_mut(),
+        ctx.bumps.activity,
+        &ctx.accounts.group,
+        &ctx.accounts.author,
+    )?;
 
     // todo: ensure a parent post was actually provided since we are creating a reply
     // if no parent post was provided, this should error
@@ -68,9 +247,21 @@ pub fn process_create_reply(ctx: Context<CreateReply>, metadata_uri: String) ->
         // we still track the parent's group for easy access
         group: ctx.accounts.parent_post.group.key(),
         author: ctx.accounts.author.key(),
+        // replies are always published by a single author
+        co_authors: Vec::new(),
+        edit_rule: PostEditRule::PrimaryAuthor,
         metadata_uri: metadata_uri,
         parent_post: Some(ctx.accounts.parent_post.key()),
         reply_count: 0,
+        report_count: 0,
+        labels,
+        // moderators can only label a post after it exists
+        moderator_labels: None,
+        moderation_status: ModerationStatus::Visible,
+        moderation_reason: 0,
+        bond_slashed: false,
+        locked: false,
+        pinned: false,
         /// reply post addresses are derived from the parent post's reply
         /// counter vice the post group's counter
         post_id: ctx.accounts.parent_post.reply_count,
@@ -87,6 +278,32 @@ pub fn process_create_reply(ctx: Context<CreateReply>, metadata_uri: String) ->
     ctx.accounts.parent_post.reply_count += 1;
     // todo: safe math
 
+    // reward the parent post's author for the engagement
+    Engagement::record(
+        ctx.accounts.engagement.as_mut(),
+        ctx.bumps.engagement,
+        &ctx.accounts.author,
+        &mut ctx.accounts.parent_author,
+    )?;
+
+    // index the reply under each of its tags for topic discovery
+    index_post_tags(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.accounts.reply.key(),
+        &tags,
+    )?;
+
+    // pay the protocol fee, if any
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.accounts.config.fees.create_post,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
     // emit an event for indexers to observe
     // todo
 
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::GenericError;
+use crate::state::{Bond, Config, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct DepositBond<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    /// the `profile.authority` that will be used to verify ownership.
+    /// the bond's lamports are deposited from here
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Bond::SPACE,
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+/// Deposit (or top up) the `profile`'s posting Bond within a PostGroup
+///
+/// note: depositing also cancels any pending withdrawal of the bond
+pub fn process_deposit_bond(ctx: Context<DepositBond>) -> Result<()> {
+    let posting_bond = ctx.accounts.group.posting_bond.ok_or(GenericError::InvalidBond)?;
+
+    // only deposit what is needed to reach the group's required amount
+    let top_up = posting_bond.amount.saturating_sub(ctx.accounts.bond.amount);
+
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.bond.to_account_info(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let bond = &mut ctx.accounts.bond;
+
+    // the account may have just been created by `init_if_needed`
+    bond.bump = ctx.bumps.bond;
+    bond.group = ctx.accounts.group.key();
+    bond.profile = ctx.accounts.profile.key();
+    bond.amount = bond.amount.checked_add(top_up).ok_or(GenericError::Overflow)?;
+    bond.withdrawable_slot = None;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
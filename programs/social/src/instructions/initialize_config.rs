@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::program::Social;
+use crate::state::{Config, Treasury};
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the program's upgrade authority, which is the only one allowed to create the Config
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ GenericError::InvalidAccount,
+    )]
+    pub program: Program<'info, Social>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ GenericError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Config::SPACE,
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Treasury::SPACE,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+/// Create the program's global Config and Treasury, setting the initial `admin`
+pub fn process_initialize_config(ctx: Context<InitializeConfig>, input: Config) -> Result<()> {
+    // validate the input
+    Config::validate_input(&input)?;
+
+    // actually store the provided data in the account
+    ctx.accounts.config.set_inner(Config {
+        bump: ctx.bumps.config,
+        admin: input.admin,
+        paused: input.paused,
+        max_tags_per_post: input.max_tags_per_post,
+        max_co_authors: input.max_co_authors,
+        fees: input.fees,
+        username_lease_duration: input.username_lease_duration,
+        username_grace_period: input.username_grace_period,
+        reservation_key: input.reservation_key,
+    });
+
+    ctx.accounts.treasury.set_inner(Treasury {
+        bump: ctx.bumps.treasury,
+        total_collected: 0,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
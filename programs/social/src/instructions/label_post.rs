@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, Post, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct LabelPost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// the group the post was published to
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        address = post.group @ GenericError::InvalidAccount,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the post being labeled
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Set the moderator content labels of a Post, overriding the author's own labels
+///
+/// note: the author's `labels` are always preserved. providing `None` clears the
+/// moderator labels, restoring the author's labels
+pub fn process_label_post(ctx: Context<LabelPost>, labels: Option<u8>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to label posts within the group
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::LABEL_POSTS,
+    )?;
+
+    // validate the input
+    if let Some(labels) = labels {
+        Post::validate_labels(labels)?;
+    }
+
+    ctx.accounts.post.moderator_labels = labels;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
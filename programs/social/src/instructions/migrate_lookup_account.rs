@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::GenericError;
+use crate::state::{Config, LookupAccount, Profile};
+use crate::utils::migrate_legacy_account;
+
+#[derive(Accounts)]
+pub struct MigrateLookupAccount<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    /// anyone may migrate a lookup account, paying for its additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: a legacy LookupAccount, which can not be deserialized until it is migrated.
+    /// its owner, size, and discriminator are verified below
+    #[account(mut, owner = crate::ID @ GenericError::InvalidAccount)]
+    pub lookup_account: UncheckedAccount<'info>,
+
+    /// CHECK: the account the lookup account points to, used to tell profile usernames
+    /// (which are leased) apart from other names. verified against the lookup account below
+    pub address: UncheckedAccount<'info>,
+}
+
+/// Grow a LookupAccount created before usernames were leased to its current size
+///
+/// note: migrated usernames are leased from the time of the migration (when the config
+/// enables leases), while other names never expire
+pub fn process_migrate_lookup_account(ctx: Context<MigrateLookupAccount>) -> Result<()> {
+    let lookup_info = ctx.accounts.lookup_account.to_account_info();
+
+    migrate_legacy_account(
+        &ctx.accounts.payer.to_account_info(),
+        &lookup_info,
+        &ctx.accounts.system_program.to_account_info(),
+        &LookupAccount::DISCRIMINATOR,
+        LookupAccount::SPACE,
+    )?;
+
+    let mut lookup_account = LookupAccount::try_deserialize(&mut &lookup_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        lookup_account.address,
+        ctx.accounts.address.key(),
+        GenericError::InvalidAccount
+    );
+
+    // squatted usernames must not be kept forever, so they are leased like any new username
+    let address = ctx.accounts.address.to_account_info();
+    let is_profile = address.owner == &crate::ID
+        && address.try_borrow_data()?.starts_with(&Profile::DISCRIMINATOR);
+    if is_profile {
+        lookup_account.expires_at = ctx
+            .accounts
+            .config
+            .username_lease_expiry(Clock::get()?.unix_timestamp);
+    }
+
+    lookup_account.try_serialize(&mut &mut lookup_info.try_borrow_mut_data()?[..])?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Post};
+use crate::utils::migrate_legacy_account;
+
+#[derive(Accounts)]
+pub struct MigratePost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    /// anyone may migrate a post, paying for its additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: a legacy Post, which may not be deserializable until it is migrated.
+    /// its owner, size, and discriminator are verified below
+    #[account(mut, owner = crate::ID @ GenericError::InvalidAccount)]
+    pub post: UncheckedAccount<'info>,
+}
+
+/// Grow a Post created by an earlier version of the program to its current size
+///
+/// note: the fields appended since the post was created start out with their default values
+/// (e.g. no `co_authors` and a `Visible` moderation status)
+pub fn process_migrate_post(ctx: Context<MigratePost>) -> Result<()> {
+    migrate_legacy_account(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.post.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &Post::DISCRIMINATOR,
+        Post::SPACE,
+    )?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
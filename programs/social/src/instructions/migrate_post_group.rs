@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::GenericError;
+use crate::state::{Config, MembershipMode, PostGroup};
+use crate::utils::migrate_legacy_account;
+
+#[derive(Accounts)]
+pub struct MigratePostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    /// anyone may migrate a post group, paying for its additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: a legacy PostGroup, which may not be deserializable until it is migrated.
+    /// its owner, size, and discriminator are verified below
+    #[account(mut, owner = crate::ID @ GenericError::InvalidAccount)]
+    pub group: UncheckedAccount<'info>,
+}
+
+/// Grow a PostGroup created by an earlier version of the program to its current size
+///
+/// note: the fields appended since the group was created start out with their default values
+/// (e.g. no posting rules), except for its `Allowlist` membership mode
+pub fn process_migrate_post_group(ctx: Context<MigratePostGroup>) -> Result<()> {
+    let group_info = ctx.accounts.group.to_account_info();
+
+    migrate_legacy_account(
+        &ctx.accounts.payer.to_account_info(),
+        &group_info,
+        &ctx.accounts.system_program.to_account_info(),
+        &PostGroup::DISCRIMINATOR,
+        PostGroup::SPACE,
+    )?;
+
+    // legacy groups only allowed their authority to post, which the zeroed bytes would
+    // otherwise turn into an `Open` group anyone is able to post in
+    let mut group = PostGroup::try_deserialize(&mut &group_info.try_borrow_data()?[..])?;
+    group.membership_mode = MembershipMode::Allowlist;
+    group.try_serialize(&mut &mut group_info.try_borrow_mut_data()?[..])?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
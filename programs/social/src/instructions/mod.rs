@@ -1,15 +1,111 @@
+mod accept_post_group_transfer;
+mod add_fee_exemption;
+mod add_member;
+mod approve_membership;
+mod assign_role;
+mod ban_from_group;
 mod change_username;
+mod close_post_group;
+mod create_channel;
+mod create_conversation;
 mod create_post;
 mod create_post_group;
 mod create_profile;
 mod create_reply;
+mod deposit_bond;
+mod initialize_config;
+mod label_post;
+mod lock_post;
+mod migrate_lookup_account;
+mod migrate_post;
+mod migrate_post_group;
+mod moderate_post;
+mod pin_post;
+mod react_to_post;
+mod release_username;
+mod remove_fee_exemption;
+mod remove_member;
+mod rename_post_group;
+mod renew_username;
+mod report_post;
+mod request_bond_withdrawal;
+mod request_membership;
+mod reserve_username;
+mod resolve_report;
+mod revoke_role;
+mod revoke_vouch;
+mod send_message;
+mod set_config_admin;
+mod set_config_paused;
+mod set_post_group_archived;
+mod set_post_group_bond;
+mod set_post_group_collection_gate;
+mod set_post_group_token_gate;
+mod slash_bond;
+mod transfer_post_group;
+mod unban_from_group;
+mod unreserve_username;
+mod update_config;
 mod update_post;
+mod update_post_group;
 mod update_profile;
+mod vouch;
+mod withdraw_bond;
+mod withdraw_group_treasury;
+mod withdraw_treasury;
 
+pub use accept_post_group_transfer::*;
+pub use add_fee_exemption::*;
+pub use add_member::*;
+pub use approve_membership::*;
+pub use assign_role::*;
+pub use ban_from_group::*;
 pub use change_username::*;
+pub use close_post_group::*;
+pub use create_channel::*;
+pub use create_conversation::*;
 pub use create_post::*;
 pub use create_post_group::*;
 pub use create_profile::*;
 pub use create_reply::*;
+pub use deposit_bond::*;
+pub use initialize_config::*;
+pub use label_post::*;
+pub use lock_post::*;
+pub use migrate_lookup_account::*;
+pub use migrate_post::*;
+pub use migrate_post_group::*;
+pub use moderate_post::*;
+pub use pin_post::*;
+pub use react_to_post::*;
+pub use release_username::*;
+pub use remove_fee_exemption::*;
+pub use remove_member::*;
+pub use rename_post_group::*;
+pub use renew_username::*;
+pub use report_post::*;
+pub use request_bond_withdrawal::*;
+pub use request_membership::*;
+pub use reserve_username::*;
+pub use resolve_report::*;
+pub use revoke_role::*;
+pub use revoke_vouch::*;
+pub use send_message::*;
+pub use set_config_admin::*;
+pub use set_config_paused::*;
+pub use set_post_group_archived::*;
+pub use set_post_group_bond::*;
+pub use set_post_group_collection_gate::*;
+pub use set_post_group_token_gate::*;
+pub use slash_bond::*;
+pub use transfer_post_group::*;
+pub use unban_from_group::*;
+pub use unreserve_username::*;
+pub use update_config::*;
 pub use update_post::*;
+pub use update_post_group::*;
 pub use update_profile::*;
+pub use vouch::*;
+pub use withdraw_bond::*;
+pub use withdraw_group_treasury::*;
+pub use withdraw_treasury::*;
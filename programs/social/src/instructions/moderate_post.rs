@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::events::PostModerated;
+use crate::state::{Config, GroupRole, ModerationStatus, Post, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct ModeratePost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// the group the post was published to
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        address = post.group @ GenericError::InvalidAccount,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the post being moderated
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Hide or remove a Post within the group, or restore it via `ModerationStatus::Visible`
+///
+/// note: the author's data is left intact so any moderation action can be reversed
+pub fn process_moderate_post(
+    ctx: Context<ModeratePost>,
+    status: ModerationStatus,
+    reason: u8,
+) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to moderate posts within the group
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::HIDE_POSTS,
+    )?;
+
+    let post = &mut ctx.accounts.post;
+
+    post.moderation_status = status;
+    post.moderation_reason = reason;
+
+    // emit an event for indexers to observe
+    emit!(PostModerated {
+        post: post.key(),
+        group: ctx.accounts.group.key(),
+        moderator: ctx.accounts.profile.key(),
+        status,
+        reason,
+    });
+
+    Ok(())
+}
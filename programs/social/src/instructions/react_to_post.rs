@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Engagement, Post, Profile, Reaction};
+
+#[derive(Accounts)]
+pub struct ReactToPost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the reacting profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// the post being reacted to
+    pub post: Account<'info, Post>,
+
+    /// the author of the post, which receives the reputation
+    #[account(
+        mut,
+        address = post.author @ GenericError::InvalidAccount,
+    )]
+    pub post_author: Account<'info, Profile>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Reaction::SPACE,
+        seeds = [
+            Reaction::PREFIX_SEED.as_ref(),
+            post.key().as_ref(),
+            // each profile can only react to a given post once
+            profile.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reaction: Account<'info, Reaction>,
+
+    /// required unless the profile is reacting to its own post
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Engagement::SPACE,
+        seeds = [
+            Engagement::PREFIX_SEED.as_ref(),
+            profile.key().as_ref(),
+            post_author.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub engagement: Option<Account<'info, Engagement>>,
+}
+
+/// React to a Post, awarding reputation to the post's author
+pub fn process_react_to_post(ctx: Context<ReactToPost>, kind: u8) -> Result<()> {
+    // actually store the provided data in the account
+    ctx.accounts.reaction.set_inner(Reaction {
+        bump: ctx.bumps.reaction,
+        post: ctx.accounts.post.key(),
+        profile: ctx.accounts.profile.key(),
+        kind,
+    });
+
+    // reward the post's author for the engagement
+    Engagement::record(
+        ctx.accounts.engagement.as_mut(),
+        ctx.bumps.engagement,
+        &ctx.accounts.profile,
+        &mut ctx.accounts.post_author,
+    )?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, LookupAccount, Profile};
+
+#[derive(Accounts)]
+pub struct ReleaseUsername<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// anyone may release an expired username, receiving its lookup account's rent
+    #[account(mut)]
+    pub releaser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        close = releaser,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            Profile::PREFIX_SEED.as_ref(),
+            profile.username.as_ref()
+        ],
+        bump = lookup_account.bump,
+        // ensure the lookup account actually points to this profile
+        constraint = lookup_account.address.key() == profile.key() @ GenericError::InvalidAccount,
+    )]
+    pub lookup_account: Account<'info, LookupAccount>,
+}
+
+/// Release a username whose lease and grace period have ended, so it can be registered again
+///
+/// note: the profile keeps working without a username, and may register a new one
+/// via `change_username`
+pub fn process_release_username(ctx: Context<ReleaseUsername>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .lookup_account
+            .is_releasable(ctx.accounts.config.username_grace_period, Clock::get()?.unix_timestamp),
+        GenericError::UsernameNotExpired
+    );
+
+    ctx.accounts.profile.username = String::new();
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
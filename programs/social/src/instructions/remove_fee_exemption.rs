@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, FeeExemption};
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemption<'info> {
+    /// the `config.admin`
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        // return the rent to the admin
+        close = admin,
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            fee_exemption.payer.as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+}
+
+/// Remove a payer from the fee allowlist, so it pays protocol fees again
+pub fn process_remove_fee_exemption(_ctx: Context<RemoveFeeExemption>) -> Result<()> {
+    // the fee exemption account is closed by anchor via the `close` constraint
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
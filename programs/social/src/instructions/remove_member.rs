@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, Membership, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct RemoveMember<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// either the group's authority or the member itself
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    #[account(
+        mut,
+        // refund the rent to the payer
+        close = payer,
+        seeds = [
+            Membership::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            membership.profile.as_ref(),
+        ],
+        bump = membership.bump,
+    )]
+    pub membership: Account<'info, Membership>,
+
+    /// the profile's role within the group.
+    /// only required when removing another profile and not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Remove a member from a PostGroup (or reject a pending request), closing its Membership
+pub fn process_remove_member(ctx: Context<RemoveMember>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // members can always leave, otherwise the profile must be allowed to manage members
+    if ctx.accounts.membership.profile != ctx.accounts.profile.key() {
+        GroupRole::authorize(
+            &ctx.accounts.group,
+            &ctx.accounts.profile.key(),
+            ctx.accounts.role.as_ref(),
+            GroupRole::MANAGE_MEMBERS,
+        )?;
+    }
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, LookupAccount, PostGroup, Profile};
+
+#[derive(Accounts)]
+#[instruction(new_name: String)]
+pub struct RenamePostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = LookupAccount::SPACE,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            PostGroup::PREFIX_SEED.as_ref(),
+            // use the new name to derive the new lookup account
+            new_name.as_ref()
+        ],
+        bump,
+    )]
+    pub new_lookup_account: Account<'info, LookupAccount>,
+
+    #[account(
+        mut,
+        // when closing the old lookup account, send the lamports to the new lookup account
+        // this makes renaming a group a negligible cost
+        close = new_lookup_account,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            PostGroup::PREFIX_SEED.as_ref(),
+            // use the current name to derive the old lookup account address
+            group.name.as_ref()
+        ],
+        bump = old_lookup_account.bump,
+        // ensure the lookup account actually points to this group
+        constraint = old_lookup_account.address.key() == group.key() @ GenericError::InvalidAccount,
+    )]
+    pub old_lookup_account: Account<'info, LookupAccount>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Rename a PostGroup, swapping its old LookupAccount for a new one
+pub fn process_rename_post_group(ctx: Context<RenamePostGroup>, new_name: String) -> Result<()> {
+    // ensure the profile is allowed to edit the group's metadata
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::EDIT_METADATA,
+    )?;
+
+    PostGroup::validate_name(&new_name)?;
+
+    // store the new lookup account's data
+    ctx.accounts.new_lookup_account.set_inner(LookupAccount {
+        bump: ctx.bumps.new_lookup_account,
+        // store the group's address for easy retrieval by anyone
+        address: ctx.accounts.group.key(),
+        // the lookup account's authority is carried over from the old lookup account
+        authority: ctx.accounts.old_lookup_account.authority,
+        // group names are never leased
+        expires_at: None,
+    });
+
+    // actually update the name
+    ctx.accounts.group.name = new_name;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
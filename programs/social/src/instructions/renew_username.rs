@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, FeeExemption, LookupAccount, Profile, Treasury};
+
+#[derive(Accounts)]
+pub struct RenewUsername<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized,
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            LookupAccount::PREFIX_SEED.as_ref(),
+            Profile::PREFIX_SEED.as_ref(),
+            profile.username.as_ref()
+        ],
+        bump = lookup_account.bump,
+        // ensure the lookup account actually points to this profile
+        constraint = lookup_account.address.key() == profile.key() @ GenericError::InvalidAccount,
+    )]
+    pub lookup_account: Account<'info, LookupAccount>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+}
+
+/// Extend the lease of a profile's username, which is allowed until the username is released
+///
+/// note: renewing an expired lease (during its grace period) starts the new lease from now.
+/// while leases are disabled (see `Config::username_lease_duration`), renewals are rejected
+pub fn process_renew_username(ctx: Context<RenewUsername>) -> Result<()> {
+    let expires_at = ctx
+        .accounts
+        .lookup_account
+        .expires_at
+        .ok_or(GenericError::UsernameNotLeased)?;
+
+    // renewing while leases are disabled would make the username permanent
+    let now = Clock::get()?.unix_timestamp;
+    let new_expires_at = ctx
+        .accounts
+        .config
+        .username_lease_expiry(expires_at.max(now))
+        .ok_or(GenericError::UsernameLeasesDisabled)?;
+
+    ctx.accounts.lookup_account.expires_at = Some(new_expires_at);
+
+    // renewing costs the same as registering the username
+    let fee = ctx.accounts.config.fees.username_price(&ctx.accounts.profile.username);
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        fee,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Post, Profile, Report, ReportOutcome};
+
+#[derive(Accounts)]
+pub struct ReportPost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `reporter.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            reporter.random_seed.as_ref()
+        ],
+        bump = reporter.bump,
+
+        // ensure the reporter's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub reporter: Account<'info, Profile>,
+
+    /// the post being reported
+    #[account(mut)]
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Report::SPACE,
+        seeds = [
+            Report::PREFIX_SEED.as_ref(),
+            post.key().as_ref(),
+            // the current `report_count` is intentionally used here
+            post.report_count.to_string().as_bytes(),
+        ],
+        bump,
+    )]
+    pub report: Account<'info, Report>,
+}
+
+/// Report a Post to the moderators of the group it was published to
+pub fn process_report_post(
+    ctx: Context<ReportPost>,
+    reason: u8,
+    evidence_uri: Option<String>,
+) -> Result<()> {
+    // validate the input
+    Report::validate_evidence_uri(evidence_uri.as_deref())?;
+
+    // actually store the provided data in the account
+    ctx.accounts.report.set_inner(Report {
+        bump: ctx.bumps.report,
+        // we are intentionally using the current `report_count` vice the next value
+        // this ensures we do not skip any index numbers
+        report_id: ctx.accounts.post.report_count,
+        post: ctx.accounts.post.key(),
+        reporter: ctx.accounts.reporter.key(),
+        reason,
+        evidence_uri,
+        // every report starts out in the moderation queue
+        outcome: ReportOutcome::Pending,
+        resolved_by: None,
+    });
+
+    // auto increment the `post.report_count` for the next report to use
+    ctx.accounts.post.report_count = ctx
+        .accounts
+        .post
+        .report_count
+        .checked_add(1)
+        .ok_or(GenericError::Overflow)?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Bond, Config, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct RequestBondWithdrawal<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// CHECK: the bond's (possibly closed) PostGroup, used to read the withdrawal cooldown.
+    /// this is always required so the cooldown can not be bypassed by omitting it
+    #[account(address = bond.group @ GenericError::InvalidAccount)]
+    pub group: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            bond.group.as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = bond.bump,
+        constraint = bond.withdrawable_slot.is_none() @ GenericError::BondWithdrawalPending,
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+/// Start withdrawing a posting Bond (step 1 of 2)
+///
+/// the bond can be withdrawn via `withdraw_bond` once the group's `cooldown_slots`
+/// have passed, giving moderators time to slash it. the profile can no longer post
+/// within the group using this bond
+pub fn process_request_bond_withdrawal(ctx: Context<RequestBondWithdrawal>) -> Result<()> {
+    // bonds for closed groups, or groups no longer requiring one, can be withdrawn immediately
+    let group = &ctx.accounts.group;
+    let cooldown_slots = if group.owner == &crate::ID && !group.data_is_empty() {
+        PostGroup::try_deserialize(&mut &group.try_borrow_data()?[..])?
+            .posting_bond
+            .map_or(0, |posting_bond| posting_bond.cooldown_slots)
+    } else {
+        0
+    };
+
+    ctx.accounts.bond.withdrawable_slot = Some(
+        Clock::get()?
+            .slot
+            .checked_add(cooldown_slots)
+            .ok_or(GenericError::Overflow)?,
+    );
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
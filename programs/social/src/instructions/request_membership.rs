@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Membership, MembershipMode, MembershipStatus, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct RequestMembership<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// the profile requesting to join the group
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // channels inheriting their parent's memberships and bans must be managed via the parent
+        constraint = group.membership_group(group.key()) == group.key() @ GenericError::InheritedFromParent,
+        // allowlist groups only accept members added by the group itself
+        constraint = group.membership_mode != MembershipMode::Allowlist @ GenericError::MembershipClosed,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Membership::SPACE,
+        seeds = [
+            Membership::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub membership: Account<'info, Membership>,
+}
+
+/// Request to join a PostGroup
+///
+/// for `MembershipMode::Approval` groups the membership is pending until approved
+/// via `approve_membership`, for `MembershipMode::Open` groups it is active immediately
+pub fn process_request_membership(ctx: Context<RequestMembership>) -> Result<()> {
+    let status = match ctx.accounts.group.membership_mode {
+        MembershipMode::Open => MembershipStatus::Active,
+        _ => MembershipStatus::Pending,
+    };
+
+    // actually store the provided data in the account
+    ctx.accounts.membership.set_inner(Membership {
+        bump: ctx.bumps.membership,
+        group: ctx.accounts.group.key(),
+        profile: ctx.accounts.profile.key(),
+        status,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
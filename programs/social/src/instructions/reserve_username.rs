@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Profile, UsernameReservation};
+
+#[derive(Accounts)]
+#[instruction(username: String)]
+pub struct ReserveUsername<'info> {
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = UsernameReservation::SPACE,
+        seeds = [
+            UsernameReservation::PREFIX_SEED.as_ref(),
+            username.as_ref()
+        ],
+        bump,
+    )]
+    pub reservation: Account<'info, UsernameReservation>,
+}
+
+/// Reserve a username, so it can only be claimed with a voucher signed by the reservation key
+///
+/// note: reserving a username does not affect a profile that has already registered it
+pub fn process_reserve_username(ctx: Context<ReserveUsername>, username: String) -> Result<()> {
+    Profile::validate_username(&username)?;
+
+    ctx.accounts.reservation.set_inner(UsernameReservation {
+        bump: ctx.bumps.reservation,
+        username,
+    });
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
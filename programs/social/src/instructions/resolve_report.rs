@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, Post, PostGroup, Profile, Report, ReportOutcome};
+
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    /// the group the reported post was published to
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        address = post.group @ GenericError::InvalidAccount,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the reported post
+    #[account(
+        address = report.post @ GenericError::InvalidAccount,
+    )]
+    pub post: Account<'info, Post>,
+
+    #[account(
+        mut,
+        seeds = [
+            Report::PREFIX_SEED.as_ref(),
+            post.key().as_ref(),
+            report.report_id.to_string().as_bytes(),
+        ],
+        bump = report.bump,
+        // reports can only be resolved once
+        constraint = report.outcome == ReportOutcome::Pending @ GenericError::ReportAlreadyResolved,
+    )]
+    pub report: Account<'info, Report>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Resolve a Report by recording the moderator's `outcome`
+pub fn process_resolve_report(ctx: Context<ResolveReport>, outcome: ReportOutcome) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to resolve reports within the group
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::RESOLVE_REPORTS,
+    )?;
+
+    // a resolved report must actually have an outcome
+    require!(outcome != ReportOutcome::Pending, GenericError::InvalidReportOutcome);
+
+    let report = &mut ctx.accounts.report;
+
+    report.outcome = outcome;
+    report.resolved_by = Some(ctx.accounts.profile.key());
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    /// the profile revoking the role
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the revoking profile's own role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+
+    #[account(
+        mut,
+        // refund the rent to the payer
+        close = payer,
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            assignee_role.profile.as_ref(),
+        ],
+        bump = assignee_role.bump,
+    )]
+    pub assignee_role: Account<'info, GroupRole>,
+}
+
+/// Revoke a Profile's role within a PostGroup, closing its GroupRole
+pub fn process_revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // profiles can always give up their own role
+    if ctx.accounts.assignee_role.profile != ctx.accounts.profile.key() {
+        // ensure the profile is allowed to revoke roles
+        GroupRole::authorize(
+            &ctx.accounts.group,
+            &ctx.accounts.profile.key(),
+            ctx.accounts.role.as_ref(),
+            GroupRole::ASSIGN_ROLES,
+        )?;
+
+        // moderators can never revoke permissions they do not have themselves
+        if ctx.accounts.group.authority != ctx.accounts.profile.key() {
+            let revoked = ctx.accounts.assignee_role.permissions;
+            require!(
+                ctx.accounts.role.as_ref().is_some_and(|role| role.has(revoked)),
+                GenericError::Unauthorized
+            );
+        }
+    }
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Endorsement, Profile, Vouch};
+
+#[derive(Accounts)]
+pub struct RevokeVouch<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `endorser.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            endorser.random_seed.as_ref()
+        ],
+        bump = endorser.bump,
+
+        // ensure the endorser's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub endorser: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            endorsee.random_seed.as_ref()
+        ],
+        bump = endorsee.bump,
+    )]
+    pub endorsee: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        // refund the rent to the payer
+        close = payer,
+        seeds = [
+            Vouch::PREFIX_SEED.as_ref(),
+            endorser.key().as_ref(),
+            endorsee.key().as_ref(),
+            vouch.skill.to_le_bytes().as_ref(),
+        ],
+        bump = vouch.bump,
+        // ensure the vouch was actually given by the endorser
+        has_one = endorser @ GenericError::Unauthorized,
+        has_one = endorsee @ GenericError::InvalidAccount,
+    )]
+    pub vouch: Account<'info, Vouch>,
+
+    #[account(
+        mut,
+        seeds = [
+            Endorsement::PREFIX_SEED.as_ref(),
+            endorser.key().as_ref(),
+            endorsee.key().as_ref(),
+        ],
+        bump = endorsement.bump,
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+}
+
+/// Revoke a previously given Vouch, closing its account
+pub fn process_revoke_vouch(ctx: Context<RevokeVouch>) -> Result<()> {
+    ctx.accounts.endorsement.vouch_count = ctx.accounts.endorsement.vouch_count.saturating_sub(1);
+
+    // the endorser no longer endorses the profile once all of their vouches are revoked
+    if ctx.accounts.endorsement.vouch_count == 0 {
+        ctx.accounts.endorsee.endorsement_count = ctx
+            .accounts
+            .endorsee
+            .endorsement_count
+            .saturating_sub(1);
+
+        ctx.accounts
+            .endorsement
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Conversation, Message, Profile};
+
+#[derive(Accounts)]
+pub struct SendMessage<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `sender.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            sender.random_seed.as_ref()
+        ],
+        bump = sender.bump,
+
+        // ensure the sender's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub sender: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            Conversation::PREFIX_SEED.as_ref(),
+            conversation.profiles[0].as_ref(),
+            conversation.profiles[1].as_ref(),
+        ],
+        bump = conversation.bump,
+        // only the participants are allowed to send messages in a conversation
+        constraint = conversation.is_participant(&sender.key()) @ GenericError::Unauthorized,
+    )]
+    pub conversation: Account<'info, Conversation>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Message::SPACE,
+        seeds = [
+            Message::PREFIX_SEED.as_ref(),
+            conversation.key().as_ref(),
+            // the current `message_count` is intentionally used here
+            conversation.message_count.to_string().as_bytes(),
+        ],
+        bump,
+    )]
+    pub message: Account<'info, Message>,
+}
+
+/// Send an encrypted Message from the `sender` within a Conversation
+///
+/// note: encryption is performed off-chain by the sender (see the `social-sdk` crate)
+/// using the recipient's `Profile.messaging_key`. the program only ever sees ciphertext
+pub fn process_send_message(
+    ctx: Context<SendMessage>,
+    nonce: [u8; 24],
+    ciphertext: Vec<u8>,
+) -> Result<()> {
+    // validate the input
+    Message::validate_ciphertext(&ciphertext)?;
+
+    // actually store the provided data in the account
+    ctx.accounts.message.set_inner(Message {
+        bump: ctx.bumps.message,
+        // we are intentionally using the current `message_count` vice the next value
+        // this ensures we do not skip any index numbers
+        message_id: ctx.accounts.conversation.message_count,
+        conversation: ctx.accounts.conversation.key(),
+        sender: ctx.accounts.sender.key(),
+        nonce,
+        ciphertext,
+    });
+
+    // auto increment the `conversation.message_count` for the next message to use
+    ctx.accounts.conversation.message_count = ctx
+        .accounts
+        .conversation
+        .message_count
+        .checked_add(1)
+        .ok_or(GenericError::Overflow)?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetConfigAdmin<'info> {
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Rotate the `admin` of the program's global Config
+pub fn process_set_config_admin(ctx: Context<SetConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+    ctx.accounts.config.admin = new_admin;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
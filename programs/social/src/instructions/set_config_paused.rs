@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct SetConfigPaused<'info> {
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Pause (or unpause) every instruction that writes data
+pub fn process_set_config_paused(ctx: Context<SetConfigPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
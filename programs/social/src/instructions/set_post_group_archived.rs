@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct SetPostGroupArchived<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+}
+
+/// Archive a PostGroup, making it read-only. existing posts remain readable
+///
+/// note: providing `false` restores the group, allowing new posts again.
+/// closed groups (see `close_post_group`) can never be restored
+pub fn process_set_post_group_archived(
+    ctx: Context<SetPostGroupArchived>,
+    archived: bool,
+) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // only the group's authority may change this
+    require_keys_eq!(
+        ctx.accounts.group.authority,
+        ctx.accounts.profile.key(),
+        GenericError::Unauthorized
+    );
+
+    // a closed group no longer owns its name, which may already belong to another group
+    require!(
+        archived || !ctx.accounts.group.name.is_empty(),
+        GenericError::GroupClosed
+    );
+
+    ctx.accounts.group.archived = archived;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
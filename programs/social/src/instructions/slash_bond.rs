@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{
+    Bond, Config, GroupRole, GroupTreasury, ModerationStatus, Post, PostGroup, Profile,
+};
+
+#[derive(Accounts)]
+pub struct SlashBond<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    /// the `profile.authority` that will be used to verify ownership.
+    /// pays to create the group's treasury on the first slash
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    #[account(
+        mut,
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+            bond.profile.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// the bond owner's post that was removed, justifying the slash.
+    /// each removed post can only be used to slash the bond once
+    #[account(
+        mut,
+        constraint = post.group == group.key() @ GenericError::InvalidAccount,
+        constraint = post.author == bond.profile @ GenericError::InvalidAccount,
+        constraint = post.moderation_status == ModerationStatus::Removed @ GenericError::PostNotRemoved,
+        constraint = !post.bond_slashed @ GenericError::BondAlreadySlashed,
+    )]
+    pub post: Account<'info, Post>,
+
+    /// the group's treasury, which receives the slashed lamports
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = GroupTreasury::SPACE,
+        seeds = [
+            GroupTreasury::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub group_treasury: Account<'info, GroupTreasury>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Slash a spammer's posting Bond for one of their removed posts, sending the group's
+/// required bond `amount` to the group's GroupTreasury (see `withdraw_group_treasury`)
+///
+/// note: the bond account is kept so the profile can top it up again via `deposit_bond`
+pub fn process_slash_bond(ctx: Context<SlashBond>) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to moderate posts within the group
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::HIDE_POSTS,
+    )?;
+
+    let posting_bond = ctx.accounts.group.posting_bond.ok_or(GenericError::InvalidBond)?;
+
+    // each removed post slashes at most the group's required bond amount
+    let amount = ctx.accounts.bond.amount.min(posting_bond.amount);
+
+    // the bond account is owned by this program, so its lamports can be moved directly
+    let bond_info = ctx.accounts.bond.to_account_info();
+    let treasury_info = ctx.accounts.group_treasury.to_account_info();
+
+    **bond_info.try_borrow_mut_lamports()? = bond_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(GenericError::Overflow)?;
+    **treasury_info.try_borrow_mut_lamports()? = treasury_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(GenericError::Overflow)?;
+
+    let group_treasury = &mut ctx.accounts.group_treasury;
+
+    // the account may have just been created by `init_if_needed`
+    group_treasury.bump = ctx.bumps.group_treasury;
+    group_treasury.group = ctx.accounts.group.key();
+    group_treasury.total_collected = group_treasury.total_collected.saturating_add(amount);
+
+    ctx.accounts.bond.amount -= amount;
+    ctx.accounts.post.bond_slashed = true;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
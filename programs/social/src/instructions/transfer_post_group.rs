@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct TransferPostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+        // ensure the post group is owned by the profile PDA
+        constraint = group.authority.key() == profile.key() @ GenericError::Unauthorized,
+        // channels are always owned by their parent, so only the parent can be transferred
+        constraint = group.parent_group.is_none() @ GenericError::InvalidChannel,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the profile the group is being transferred to
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            new_authority.random_seed.as_ref()
+        ],
+        bump = new_authority.bump,
+    )]
+    pub new_authority: Account<'info, Profile>,
+}
+
+/// Start transferring a PostGroup to another Profile (step 1 of 2)
+///
+/// the transfer is only completed once the `new_authority` accepts it via
+/// `accept_post_group_transfer`. providing the current profile as the
+/// `new_authority` cancels any pending transfer
+pub fn process_transfer_post_group(ctx: Context<TransferPostGroup>) -> Result<()> {
+    let new_authority = ctx.accounts.new_authority.key();
+
+    ctx.accounts.group.pending_authority = if new_authority == ctx.accounts.profile.key() {
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
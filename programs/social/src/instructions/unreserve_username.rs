@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, UsernameReservation};
+
+#[derive(Accounts)]
+pub struct UnreserveUsername<'info> {
+    /// the `config.admin`
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        // return the rent to the admin
+        close = admin,
+        seeds = [
+            UsernameReservation::PREFIX_SEED.as_ref(),
+            reservation.username.as_ref()
+        ],
+        bump = reservation.bump,
+    )]
+    pub reservation: Account<'info, UsernameReservation>,
+}
+
+/// Remove a username's reservation, so anyone can register it again
+pub fn process_unreserve_username(_ctx: Context<UnreserveUsername>) -> Result<()> {
+    // the reservation account is closed by anchor via the `close` constraint
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::Config;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Update the tunable limits and fees of the program's global Config
+///
+/// note: the `admin` and `paused` flag are changed via `set_config_admin`
+/// and `set_config_paused`
+pub fn process_update_config(ctx: Context<UpdateConfig>, input: Config) -> Result<()> {
+    // validate the input
+    Config::validate_input(&input)?;
+
+    let config = &mut ctx.accounts.config;
+
+    // update the desired config details
+    config.max_tags_per_post = input.max_tags_per_post;
+    config.max_co_authors = input.max_co_authors;
+    config.fees = input.fees;
+    config.username_lease_duration = input.username_lease_duration;
+    config.username_grace_period = input.username_grace_period;
+    config.reservation_key = input.reservation_key;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
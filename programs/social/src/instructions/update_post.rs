@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GenericError;
-use crate::state::{Post, Profile};
+use crate::state::{Config, Post, Profile};
 
 #[derive(Accounts)]
 #[instruction(metadata_uri: String)]
 pub struct UpdatePost<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -34,17 +42,24 @@ pub struct UpdatePost<'info> {
             post.post_id.to_string().as_bytes(),
         ],
         bump = post.bump,
-        // ensure the provided author owns this Post
-        has_one = author,
+        // ensure the provided author is allowed to edit this Post
+        constraint = post.can_edit(&author.key()) @ GenericError::Unauthorized,
     )]
     pub post: Account<'info, Post>,
 }
 
-pub fn process_update_post(ctx: Context<UpdatePost>, metadata_uri: String) -> Result<()> {
+pub fn process_update_post(
+    ctx: Context<UpdatePost>,
+    metadata_uri: String,
+    labels: u8,
+) -> Result<()> {
     Post::validate_uri(&metadata_uri)?;
+    Post::validate_labels(labels)?;
 
     // only update the desired data
+    // note: any `moderator_labels` are intentionally left in place
     ctx.accounts.post.metadata_uri = metadata_uri;
+    ctx.accounts.post.labels = labels;
 
     // emit an event for indexers to observe
     // todo
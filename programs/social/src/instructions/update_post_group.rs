@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupRole, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct UpdatePostGroup<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    /// the profile's role within the group.
+    /// only required when the profile is not the group's authority
+    #[account(
+        seeds = [
+            GroupRole::PREFIX_SEED.as_ref(),
+            // channels may inherit the moderator roles of their parent
+            group.moderation_group(group.key()).as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = role.bump,
+    )]
+    pub role: Option<Account<'info, GroupRole>>,
+}
+
+/// Update the details of a PostGroup
+///
+/// note: the group's `name` is changed via `rename_post_group`, except for channels
+/// since they do not have a LookupAccount
+///
+/// changing the group's posting rules requires `GroupRole::MANAGE_MEMBERS`, and only the
+/// group's authority can change what a channel inherits from its parent
+pub fn process_update_post_group(ctx: Context<UpdatePostGroup>, input: PostGroup) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // ensure the profile is allowed to edit the group's metadata
+    GroupRole::authorize(
+        &ctx.accounts.group,
+        &ctx.accounts.profile.key(),
+        ctx.accounts.role.as_ref(),
+        GroupRole::EDIT_METADATA,
+    )?;
+
+    PostGroup::validate_input(&input)?;
+
+    // the posting rules control who can post, so they are not considered metadata
+    let group = &ctx.accounts.group;
+    if group.min_reputation != input.min_reputation
+        || group.membership_mode != input.membership_mode
+        || group.rate_limit != input.rate_limit
+    {
+        GroupRole::authorize(
+            group,
+            &ctx.accounts.profile.key(),
+            ctx.accounts.role.as_ref(),
+            GroupRole::MANAGE_MEMBERS,
+        )?;
+    }
+
+    // no longer inheriting from the parent would escape its bans and moderators
+    let inheritance_changed = group.inherit_membership != input.inherit_membership
+        || group.inherit_moderation != input.inherit_moderation;
+    if group.parent_group.is_some() && inheritance_changed {
+        require_keys_eq!(
+            group.authority,
+            ctx.accounts.profile.key(),
+            GenericError::Unauthorized
+        );
+    }
+
+    let group = &mut ctx.accounts.group;
+
+    // update the desired group details
+    group.description = input.description;
+    group.image_uri = input.image_uri;
+    group.metadata_uri = input.metadata_uri;
+    group.content_hash = input.content_hash;
+    group.category = input.category;
+
+    // channels may override what they inherit from their parent
+    if group.parent_group.is_some() {
+        group.name = input.name;
+        group.inherit_membership = input.inherit_membership;
+        group.inherit_moderation = input.inherit_moderation;
+    }
+    group.min_reputation = input.min_reputation;
+    group.membership_mode = input.membership_mode;
+    group.rate_limit = input.rate_limit;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::GenericError;
-use crate::state::Profile;
+use crate::state::{Config, Profile};
 
 #[derive(Accounts)]
 #[instruction(input: Profile)]
 pub struct UpdateProfile<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
     system_program: Program<'info, System>,
 
     #[account(mut)]
@@ -29,7 +37,9 @@ pub struct UpdateProfile<'info> {
 }
 
 pub fn process_update_profile(ctx: Context<UpdateProfile>, input: Profile) -> Result<()> {
-    Profile::validate_input(&input)?;
+    // the username is only changed via `change_username`, so it is not validated here
+    // (e.g. it is empty once released)
+    Profile::validate_details(&input)?;
 
     let profile = &mut ctx.accounts.profile;
 
@@ -37,6 +47,7 @@ pub fn process_update_profile(ctx: Context<UpdateProfile>, input: Profile) -> Re
     profile.name = input.name;
     profile.image_uri = input.image_uri;
     profile.metadata_uri = input.metadata_uri;
+    profile.messaging_key = input.messaging_key;
 
     // emit an event for indexers to observe
     // todo
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Endorsement, FeeExemption, Profile, Treasury, Vouch};
+
+#[derive(Accounts)]
+#[instruction(skill: u16)]
+pub struct CreateVouch<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// the `endorser.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            endorser.random_seed.as_ref()
+        ],
+        bump = endorser.bump,
+
+        // ensure the endorser's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub endorser: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            endorsee.random_seed.as_ref()
+        ],
+        bump = endorsee.bump,
+        // a profile cannot vouch for itself, nor for other profiles of the same authority
+        constraint = endorsee.key() != endorser.key() @ GenericError::InvalidVouch,
+        constraint = endorsee.authority != endorser.authority @ GenericError::InvalidVouch,
+    )]
+    pub endorsee: Account<'info, Profile>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Vouch::SPACE,
+        seeds = [
+            Vouch::PREFIX_SEED.as_ref(),
+            endorser.key().as_ref(),
+            endorsee.key().as_ref(),
+            skill.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub vouch: Account<'info, Vouch>,
+
+    /// tracks the vouches between the two profiles, so each endorser is only counted once
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Endorsement::SPACE,
+        seeds = [
+            Endorsement::PREFIX_SEED.as_ref(),
+            endorser.key().as_ref(),
+            endorsee.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// the program's treasury.
+    /// only required when a protocol fee is charged (i.e. non-zero and the payer is not exempt)
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, Treasury>>,
+
+    /// the payer's fee exemption.
+    /// only required when the payer is exempt from paying protocol fees
+    #[account(
+        seeds = [
+            FeeExemption::PREFIX_SEED.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = fee_exemption.bump,
+    )]
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+}
+
+/// Vouch for the `endorsee` Profile, optionally for a specific skill/topic
+pub fn process_vouch(ctx: Context<CreateVouch>, skill: u16) -> Result<()> {
+    // actually store the provided data in the account
+    ctx.accounts.vouch.set_inner(Vouch {
+        bump: ctx.bumps.vouch,
+        endorser: ctx.accounts.endorser.key(),
+        endorsee: ctx.accounts.endorsee.key(),
+        skill,
+    });
+
+    // the account may have just been created by `init_if_needed`
+    let endorsement = &mut ctx.accounts.endorsement;
+    endorsement.bump = ctx.bumps.endorsement;
+    endorsement.endorser = ctx.accounts.endorser.key();
+    endorsement.endorsee = ctx.accounts.endorsee.key();
+
+    // track the total endorsers of the profile for easy access, counting each endorser
+    // only once regardless of how many skills they vouched for
+    if endorsement.vouch_count == 0 {
+        ctx.accounts.endorsee.endorsement_count = ctx
+            .accounts
+            .endorsee
+            .endorsement_count
+            .checked_add(1)
+            .ok_or(GenericError::Overflow)?;
+    }
+
+    endorsement.vouch_count = endorsement
+        .vouch_count
+        .checked_add(1)
+        .ok_or(GenericError::Overflow)?;
+
+    // charge the protocol fee (if any), so endorsements can not be farmed for free
+    Treasury::collect_fee(
+        ctx.accounts.treasury.as_mut(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.accounts.config.fees.vouch,
+        ctx.accounts.fee_exemption.as_ref(),
+    )?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Bond, Config, Profile};
+
+#[derive(Accounts)]
+pub struct WithdrawBond<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership.
+    /// the bond's lamports (and rent) are returned here
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            Bond::PREFIX_SEED.as_ref(),
+            bond.group.as_ref(),
+            profile.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+}
+
+/// Withdraw a posting Bond after its cooldown has passed (step 2 of 2)
+///
+/// note: the group account is not required, allowing bonds to be withdrawn
+/// from groups that have since been closed (see `request_bond_withdrawal`)
+pub fn process_withdraw_bond(ctx: Context<WithdrawBond>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+
+    require!(
+        ctx.accounts
+            .bond
+            .withdrawable_slot
+            .is_some_and(|withdrawable_slot| slot >= withdrawable_slot),
+        GenericError::BondCooldown
+    );
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
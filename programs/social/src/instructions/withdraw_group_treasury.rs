@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, GroupTreasury, PostGroup, Profile};
+
+#[derive(Accounts)]
+pub struct WithdrawGroupTreasury<'info> {
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // no writes are allowed while the program is paused
+        constraint = !config.paused @ GenericError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// the `profile.authority` that will be used to verify ownership
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            Profile::PREFIX_SEED.as_ref(),
+            profile.random_seed.as_ref()
+        ],
+        bump = profile.bump,
+
+        // ensure the profile's authority is actually approving this
+        has_one = authority @ GenericError::Unauthorized
+    )]
+    pub profile: Account<'info, Profile>,
+
+    #[account(
+        seeds = [
+            group.prefix_seed(),
+            group.random_seed.as_ref()
+        ],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, PostGroup>,
+
+    /// the group's parent, whose authority also owns the group.
+    /// only required when the group is a channel
+    #[account(
+        seeds = [
+            PostGroup::PREFIX_SEED.as_ref(),
+            parent_group.random_seed.as_ref()
+        ],
+        bump = parent_group.bump,
+    )]
+    pub parent_group: Option<Account<'info, PostGroup>>,
+
+    #[account(
+        mut,
+        seeds = [
+            GroupTreasury::PREFIX_SEED.as_ref(),
+            group.key().as_ref(),
+        ],
+        bump = group_treasury.bump,
+    )]
+    pub group_treasury: Account<'info, GroupTreasury>,
+
+    /// CHECK: any account chosen by the group's authority may receive the withdrawn lamports
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// Withdraw slashed posting bonds from a PostGroup's GroupTreasury
+pub fn process_withdraw_group_treasury(
+    ctx: Context<WithdrawGroupTreasury>,
+    amount: u64,
+) -> Result<()> {
+    // channels are always owned by their parent's current authority
+    PostGroup::resolve_authority(&mut ctx.accounts.group, ctx.accounts.parent_group.as_ref())?;
+
+    // only the group's authority may withdraw
+    require_keys_eq!(
+        ctx.accounts.group.authority,
+        ctx.accounts.profile.key(),
+        GenericError::Unauthorized
+    );
+
+    let treasury_info = ctx.accounts.group_treasury.to_account_info();
+    let destination_info = ctx.accounts.destination.to_account_info();
+
+    // the treasury must always remain rent exempt
+    let rent = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent);
+    require!(amount <= available, GenericError::InsufficientTreasuryBalance);
+
+    // the treasury account is owned by this program, so its lamports can be moved directly
+    **treasury_info.try_borrow_mut_lamports()? = treasury_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(GenericError::Overflow)?;
+    **destination_info.try_borrow_mut_lamports()? = destination_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(GenericError::Overflow)?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
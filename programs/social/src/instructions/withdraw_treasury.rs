@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Config, Treasury};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// the `config.admin`
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::PREFIX_SEED.as_ref()],
+        bump = config.bump,
+        // ensure the config's admin is actually approving this
+        has_one = admin @ GenericError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::PREFIX_SEED.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: any account chosen by the admin may receive the withdrawn fees
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// Withdraw collected protocol fees from the Treasury
+pub fn process_withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let destination_info = ctx.accounts.destination.to_account_info();
+
+    // the treasury must always remain rent exempt
+    let rent = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent);
+    require!(amount <= available, GenericError::InsufficientTreasuryBalance);
+
+    // the treasury account is owned by this program, so its lamports can be moved directly
+    **treasury_info.try_borrow_mut_lamports()? = treasury_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(GenericError::Overflow)?;
+    **destination_info.try_borrow_mut_lamports()? = destination_info
+        .lamports()
+        .checked_add(amount)
+        .ok_or(GenericError::Overflow)?;
+
+    // emit an event for indexers to observe
+    // todo
+
+    Ok(())
+}
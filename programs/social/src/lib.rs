@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use crate::instructions::*;
 use crate::state::*;
@@ -14,6 +16,33 @@ declare_id!("EsNiAoa8UtvZ81e1um5KLmW79xTq8DzyKvX4nZAG9TL6");
 pub mod social {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, input: Config) -> Result<()> {
+        process_initialize_config(ctx, input)
+    }
+    pub fn update_config(ctx: Context<UpdateConfig>, input: Config) -> Result<()> {
+        process_update_config(ctx, input)
+    }
+    pub fn set_config_paused(ctx: Context<SetConfigPaused>, paused: bool) -> Result<()> {
+        process_set_config_paused(ctx, paused)
+    }
+    pub fn set_config_admin(ctx: Context<SetConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+        process_set_config_admin(ctx, new_admin)
+    }
+    pub fn add_fee_exemption(ctx: Context<AddFeeExemption>, exempt_payer: Pubkey) -> Result<()> {
+        process_add_fee_exemption(ctx, exempt_payer)
+    }
+    pub fn remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+        process_remove_fee_exemption(ctx)
+    }
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        process_withdraw_treasury(ctx, amount)
+    }
+    pub fn reserve_username(ctx: Context<ReserveUsername>, username: String) -> Result<()> {
+        process_reserve_username(ctx, username)
+    }
+    pub fn unreserve_username(ctx: Context<UnreserveUsername>) -> Result<()> {
+        process_unreserve_username(ctx)
+    }
     pub fn create_profile(ctx: Context<CreateProfile>, input: Profile) -> Result<()> {
         process_create_profile(ctx, input)
     }
@@ -27,20 +56,171 @@ pub mod social {
     ) -> Result<()> {
         process_change_username(ctx, random_seed, new_username)
     }
-    pub fn create_post(ctx: Context<CreatePost>, metadata_uri: String) -> Result<()> {
-        process_create_post(ctx, metadata_uri)
+    pub fn migrate_lookup_account(ctx: Context<MigrateLookupAccount>) -> Result<()> {
+        process_migrate_lookup_account(ctx)
     }
-    pub fn create_post_group(
-        ctx: Context<CreatePostGroup>,
-        random_seed: [u8; 32],
-        name: String,
+    pub fn renew_username(ctx: Context<RenewUsername>) -> Result<()> {
+        process_renew_username(ctx)
+    }
+    pub fn release_username(ctx: Context<ReleaseUsername>) -> Result<()> {
+        process_release_username(ctx)
+    }
+    pub fn create_post<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePost<'info>>,
+        metadata_uri: String,
+        tags: Vec<String>,
+        edit_rule: PostEditRule,
+        labels: u8,
+    ) -> Result<()> {
+        process_create_post(ctx, metadata_uri, tags, edit_rule, labels)
+    }
+    pub fn create_post_group(ctx: Context<CreatePostGroup>, input: PostGroup) -> Result<()> {
+        process_create_post_group(ctx, input)
+    }
+    pub fn create_channel(ctx: Context<CreateChannel>, input: PostGroup) -> Result<()> {
+        process_create_channel(ctx, input)
+    }
+    pub fn update_post_group(ctx: Context<UpdatePostGroup>, input: PostGroup) -> Result<()> {
+        process_update_post_group(ctx, input)
+    }
+    pub fn rename_post_group(ctx: Context<RenamePostGroup>, new_name: String) -> Result<()> {
+        process_rename_post_group(ctx, new_name)
+    }
+    pub fn transfer_post_group(ctx: Context<TransferPostGroup>) -> Result<()> {
+        process_transfer_post_group(ctx)
+    }
+    pub fn accept_post_group_transfer(ctx: Context<AcceptPostGroupTransfer>) -> Result<()> {
+        process_accept_post_group_transfer(ctx)
+    }
+    pub fn set_post_group_token_gate(
+        ctx: Context<SetPostGroupTokenGate>,
+        token_gate: Option<TokenGate>,
+    ) -> Result<()> {
+        process_set_post_group_token_gate(ctx, token_gate)
+    }
+    pub fn set_post_group_collection_gate(
+        ctx: Context<SetPostGroupCollectionGate>,
+        collection: Option<Pubkey>,
+    ) -> Result<()> {
+        process_set_post_group_collection_gate(ctx, collection)
+    }
+    pub fn set_post_group_archived(
+        ctx: Context<SetPostGroupArchived>,
+        archived: bool,
+    ) -> Result<()> {
+        process_set_post_group_archived(ctx, archived)
+    }
+    pub fn close_post_group(ctx: Context<ClosePostGroup>) -> Result<()> {
+        process_close_post_group(ctx)
+    }
+    pub fn migrate_post_group(ctx: Context<MigratePostGroup>) -> Result<()> {
+        process_migrate_post_group(ctx)
+    }
+    pub fn set_post_group_bond(
+        ctx: Context<SetPostGroupBond>,
+        posting_bond: Option<PostingBond>,
+    ) -> Result<()> {
+        process_set_post_group_bond(ctx, posting_bond)
+    }
+    pub fn deposit_bond(ctx: Context<DepositBond>) -> Result<()> {
+        process_deposit_bond(ctx)
+    }
+    pub fn request_bond_withdrawal(ctx: Context<RequestBondWithdrawal>) -> Result<()> {
+        process_request_bond_withdrawal(ctx)
+    }
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>) -> Result<()> {
+        process_withdraw_bond(ctx)
+    }
+    pub fn slash_bond(ctx: Context<SlashBond>) -> Result<()> {
+        process_slash_bond(ctx)
+    }
+    pub fn withdraw_group_treasury(
+        ctx: Context<WithdrawGroupTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        process_withdraw_group_treasury(ctx, amount)
+    }
+    pub fn add_member(ctx: Context<AddMember>) -> Result<()> {
+        process_add_member(ctx)
+    }
+    pub fn request_membership(ctx: Context<RequestMembership>) -> Result<()> {
+        process_request_membership(ctx)
+    }
+    pub fn approve_membership(ctx: Context<ApproveMembership>) -> Result<()> {
+        process_approve_membership(ctx)
+    }
+    pub fn remove_member(ctx: Context<RemoveMember>) -> Result<()> {
+        process_remove_member(ctx)
+    }
+    pub fn assign_role(ctx: Context<AssignRole>, permissions: u16) -> Result<()> {
+        process_assign_role(ctx, permissions)
+    }
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        process_revoke_role(ctx)
+    }
+    pub fn ban_from_group(ctx: Context<BanFromGroup>, expires_slot: Option<u64>) -> Result<()> {
+        process_ban_from_group(ctx, expires_slot)
+    }
+    pub fn unban_from_group(ctx: Context<UnbanFromGroup>) -> Result<()> {
+        process_unban_from_group(ctx)
+    }
+    pub fn create_reply<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateReply<'info>>,
+        metadata_uri: String,
+        tags: Vec<String>,
+        labels: u8,
     ) -> Result<()> {
-        process_create_post_group(ctx, random_seed, name)
+        process_create_reply(ctx, metadata_uri, tags, labels)
+    }
+    pub fn update_post(ctx: Context<UpdatePost>, metadata_uri: String, labels: u8) -> Result<()> {
+        process_update_post(ctx, metadata_uri, labels)
+    }
+    pub fn migrate_post(ctx: Context<MigratePost>) -> Result<()> {
+        process_migrate_post(ctx)
+    }
+    pub fn label_post(ctx: Context<LabelPost>, labels: Option<u8>) -> Result<()> {
+        process_label_post(ctx, labels)
+    }
+    pub fn moderate_post(
+        ctx: Context<ModeratePost>,
+        status: ModerationStatus,
+        reason: u8,
+    ) -> Result<()> {
+        process_moderate_post(ctx, status, reason)
+    }
+    pub fn lock_post(ctx: Context<LockPost>, locked: bool) -> Result<()> {
+        process_lock_post(ctx, locked)
+    }
+    pub fn pin_post(ctx: Context<PinPost>, pinned: bool) -> Result<()> {
+        process_pin_post(ctx, pinned)
+    }
+    pub fn report_post(
+        ctx: Context<ReportPost>,
+        reason: u8,
+        evidence_uri: Option<String>,
+    ) -> Result<()> {
+        process_report_post(ctx, reason, evidence_uri)
+    }
+    pub fn resolve_report(ctx: Context<ResolveReport>, outcome: ReportOutcome) -> Result<()> {
+        process_resolve_report(ctx, outcome)
+    }
+    pub fn create_conversation(ctx: Context<CreateConversation>) -> Result<()> {
+        process_create_conversation(ctx)
+    }
+    pub fn send_message(
+        ctx: Context<SendMessage>,
+        nonce: [u8; 24],
+        ciphertext: Vec<u8>,
+    ) -> Result<()> {
+        process_send_message(ctx, nonce, ciphertext)
+    }
+    pub fn vouch(ctx: Context<CreateVouch>, skill: u16) -> Result<()> {
+        process_vouch(ctx, skill)
     }
-    pub fn create_reply(ctx: Context<CreateReply>, metadata_uri: String) -> Result<()> {
-        process_create_reply(ctx, metadata_uri)
+    pub fn revoke_vouch(ctx: Context<RevokeVouch>) -> Result<()> {
+        process_revoke_vouch(ctx)
     }
-    pub fn update_post(ctx: Context<UpdatePost>, metadata_uri: String) -> Result<()> {
-        process_update_post(ctx, metadata_uri)
+    pub fn react_to_post(ctx: Context<ReactToPost>, kind: u8) -> Result<()> {
+        process_react_to_post(ctx, kind)
     }
 }
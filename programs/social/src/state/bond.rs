@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{PostGroup, Profile};
+
+#[account]
+#[derive(Default)]
+pub struct Bond {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the bond was deposited for
+    pub group: Pubkey,
+
+    /// profile that deposited the bond
+    pub profile: Pubkey,
+
+    /// lamports held in escrow by this account (excluding its rent)
+    pub amount: u64,
+
+    /// slot the bond can be withdrawn at, once a withdrawal has been requested.
+    /// a bond being withdrawn no longer allows its profile to post
+    pub withdrawable_slot: Option<u64>,
+}
+
+impl Bond {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "bond";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// validate the `author` has deposited the bond required by the `group`,
+    /// when the PostGroup has a `posting_bond`
+    pub fn validate(
+        bond: Option<&Account<Bond>>,
+        group: &Account<PostGroup>,
+        author: &Account<Profile>,
+    ) -> Result<()> {
+        // the group's own authority never needs a bond
+        let posting_bond = match group.posting_bond {
+            Some(posting_bond) if group.authority != author.key() => posting_bond,
+            _ => return Ok(()),
+        };
+
+        let bond = bond.ok_or(GenericError::MissingBond)?;
+
+        require!(bond.withdrawable_slot.is_none(), GenericError::BondWithdrawalPending);
+        require!(bond.amount >= posting_bond.amount, GenericError::InsufficientBond);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{Post, Tag};
+
+#[account]
+#[derive(Default)]
+pub struct Config {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// account with authority over the program's global Config
+    pub admin: Pubkey,
+
+    /// when paused, every instruction that writes data is rejected
+    pub paused: bool,
+
+    /// max number of tags allowed on a single post
+    pub max_tags_per_post: u8,
+
+    /// max number of co-authors allowed on a single post
+    pub max_co_authors: u8,
+
+    /// lamport fees paid into the program's Treasury
+    pub fees: ProtocolFees,
+
+    /// seconds a username is leased for when registered or renewed.
+    /// a duration of `0` disables leases, so usernames never expire
+    pub username_lease_duration: i64,
+
+    /// seconds after a lease expires that only its profile can still renew the username
+    pub username_grace_period: i64,
+
+    /// ed25519 key that signs the vouchers needed to claim a reserved username.
+    /// without a reservation key, reserved usernames can not be claimed
+    pub reservation_key: Option<Pubkey>,
+}
+
+/// Lamport fees charged by the program, where a fee of `0` is disabled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolFees {
+    /// fee charged by `create_profile`
+    pub create_profile: u64,
+
+    /// fee charged by `change_username`
+    pub change_username: u64,
+
+    /// fee charged by `create_post_group`
+    pub create_post_group: u64,
+
+    /// fee charged for every post, including replies
+    pub create_post: u64,
+
+    /// fee charged for every vouch, so becoming an established engager is never free
+    /// (see `Engagement::is_established_engager`)
+    pub vouch: u64,
+
+    /// extra fee charged when registering a username (via `create_profile` or
+    /// `change_username`), indexed by the username's length in characters.
+    /// the last tier applies to every longer username
+    pub username_prices: [u64; 5],
+}
+
+impl ProtocolFees {
+    /// get the registration price of a `username`, based on its length
+    pub fn username_price(&self, username: &str) -> u64 {
+        // count characters (not bytes) so multi-byte names are priced by their visible length
+        let tier = username
+            .chars()
+            .count()
+            .saturating_sub(1)
+            .min(self.username_prices.len() - 1);
+
+        self.username_prices[tier]
+    }
+}
+
+impl Config {
+    /// static prefix seed string used to derive the PDA
+    pub const PREFIX_SEED: &str = "config";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// validate the tunable limits, which can never exceed the program's hard limits
+    pub fn validate_input(input: &Config) -> Result<()> {
+        require!(
+            usize::from(input.max_tags_per_post) <= Tag::MAX_TAGS_PER_POST,
+            GenericError::InvalidConfig
+        );
+        require!(
+            usize::from(input.max_co_authors) <= Post::MAX_CO_AUTHORS,
+            GenericError::InvalidConfig
+        );
+        require!(
+            input.username_lease_duration >= 0 && input.username_grace_period >= 0,
+            GenericError::InvalidConfig
+        );
+
+        Ok(())
+    }
+
+    /// get the expiry of a username lease that starts at `start`,
+    /// or `None` when leases are disabled
+    pub fn username_lease_expiry(&self, start: i64) -> Option<i64> {
+        match self.username_lease_duration {
+            0 => None,
+            duration => Some(start.saturating_add(duration)),
+        }
+    }
+
+    /// ensure a new post does not exceed the tunable tag and co-author limits
+    pub fn validate_post_limits(&self, tag_count: usize, co_author_count: usize) -> Result<()> {
+        require!(
+            tag_count <= usize::from(self.max_tags_per_post),
+            GenericError::TooManyTags
+        );
+        require!(
+            co_author_count <= usize::from(self.max_co_authors),
+            GenericError::TooManyCoAuthors
+        );
+
+        Ok(())
+    }
+}
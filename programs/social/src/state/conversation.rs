@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Conversation {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// the two participating Profiles, always stored in sorted order.
+    /// note: the sorted profile addresses are also used as the seeds for the PDA
+    pub profiles: [Pubkey; 2],
+
+    /// counter for total number of messages within the Conversation.
+    /// note: the `message_count` will be used as a seed for child Messages.
+    /// effecively allowing us to easily enumerate messages
+    pub message_count: u32,
+}
+
+impl Conversation {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "conversation";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// sort two profile addresses into the order used to derive the Conversation PDA
+    pub fn sort_profiles(a: Pubkey, b: Pubkey) -> [Pubkey; 2] {
+        if a <= b {
+            [a, b]
+        } else {
+            [b, a]
+        }
+    }
+
+    /// check if the given profile is one of the Conversation's participants
+    pub fn is_participant(&self, profile: &Pubkey) -> bool {
+        self.profiles.contains(profile)
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Endorsement {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// profile giving the endorsement
+    pub endorser: Pubkey,
+
+    /// profile receiving the endorsement
+    pub endorsee: Pubkey,
+
+    /// total number of active Vouches (across all skills) `endorser` has given `endorsee`.
+    /// note: the endorsee's `endorsement_count` only counts each endorser once
+    pub vouch_count: u32,
+}
+
+impl Endorsement {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "endorsement";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
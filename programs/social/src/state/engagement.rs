@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::Profile;
+
+#[account]
+#[derive(Default)]
+pub struct Engagement {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// profile that engaged (e.g. reacted or replied)
+    pub from: Pubkey,
+
+    /// profile whose post was engaged with
+    pub to: Pubkey,
+
+    /// total reputation points `from` has given to `to`
+    pub count: u32,
+}
+
+impl Engagement {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "engagement";
+
+    /// max reputation points a single profile can give to another profile.
+    /// this caps the effect of repeated actions from the same profile
+    pub const MAX_REPUTATION_PER_PROFILE: u32 = 10;
+
+    /// min distinct endorsers a profile needs before its engagement awards reputation
+    pub const MIN_ENGAGER_ENDORSEMENTS: u32 = 3;
+
+    /// min reputation a profile needs before its engagement awards reputation
+    /// (when it does not have enough endorsements)
+    pub const MIN_ENGAGER_REPUTATION: u64 = 10;
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// check if an engagement between two profiles is a self-engagement,
+    /// which never affects reputation
+    pub fn is_self_engagement(from: &Account<Profile>, to: &Account<Profile>) -> bool {
+        from.key() == to.key() || from.authority == to.authority
+    }
+
+    /// check if the `from` profile is established enough for its engagement to award reputation.
+    ///
+    /// note: since creating profiles is cheap, the per-profile cap alone does not stop a single
+    /// user from farming reputation with many fresh profiles. only profiles that were endorsed by
+    /// several distinct profiles (or earned reputation themselves) are trusted to award it.
+    ///
+    /// endorsements can only come from profiles of other authorities, and every vouch pays the
+    /// protocol's `fees.vouch`, so establishing a profile costs at least
+    /// `MIN_ENGAGER_ENDORSEMENTS` vouch fees
+    pub fn is_established_engager(from: &Account<Profile>) -> bool {
+        from.endorsement_count >= Self::MIN_ENGAGER_ENDORSEMENTS
+            || from.reputation >= Self::MIN_ENGAGER_REPUTATION
+    }
+
+    /// record an engagement from the `from` profile to the `to` profile,
+    /// awarding reputation to the `to` profile until the per-profile cap is reached
+    /// (only when `from` is an established engager)
+    pub fn record(
+        engagement: Option<&mut Account<Engagement>>,
+        bump: u8,
+        from: &Account<Profile>,
+        to: &mut Account<Profile>,
+    ) -> Result<()> {
+        if Self::is_self_engagement(from, to) {
+            return Ok(());
+        }
+
+        let engagement = engagement.ok_or(GenericError::MissingEngagementAccount)?;
+
+        // the account may have just been created by `init_if_needed`
+        engagement.bump = bump;
+        engagement.from = from.key();
+        engagement.to = to.key();
+
+        if engagement.count < Self::MAX_REPUTATION_PER_PROFILE
+            && Self::is_established_engager(from)
+        {
+            engagement.count += 1;
+            to.reputation = to.reputation.checked_add(1).ok_or(GenericError::Overflow)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct FeeExemption {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// payer that is exempt from paying protocol fees
+    pub payer: Pubkey,
+}
+
+impl FeeExemption {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "fee_exemption";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
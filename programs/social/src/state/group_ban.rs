@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::PostGroup;
+
+#[account]
+#[derive(Default)]
+pub struct GroupBan {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the ban is for
+    pub group: Pubkey,
+
+    /// the banned profile
+    pub profile: Pubkey,
+
+    /// profile of the group authority or moderator that issued the ban
+    pub banned_by: Pubkey,
+
+    /// slot the ban expires at. a `None` value means the ban is permanent
+    pub expires_slot: Option<u64>,
+}
+
+impl GroupBan {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "group_ban";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// check if the ban is still in effect at the given `slot`
+    pub fn is_active(&self, slot: u64) -> bool {
+        match self.expires_slot {
+            Some(expires_slot) => slot < expires_slot,
+            None => true,
+        }
+    }
+
+    /// validate the profile is not banned from posting, given its (possibly uninitialized)
+    /// GroupBan PDA. the caller is responsible for verifying the PDA's address
+    pub fn validate_not_banned(ban: &AccountInfo) -> Result<()> {
+        // no ban has ever been created for the profile
+        if ban.owner != &crate::ID || ban.data_is_empty() {
+            return Ok(());
+        }
+
+        let ban = GroupBan::try_deserialize(&mut &ban.try_borrow_data()?[..])?;
+        require!(!ban.is_active(Clock::get()?.slot), GenericError::Banned);
+
+        Ok(())
+    }
+
+    /// validate the profile is not banned from the parent of a channel `group`, given its
+    /// (possibly uninitialized) GroupBan PDA within the parent.
+    ///
+    /// note: a channel with its own memberships also has its own bans, but those never
+    /// lift a ban from the parent community
+    pub fn validate_not_banned_from_parent(
+        group: &PostGroup,
+        parent_ban: Option<&UncheckedAccount>,
+    ) -> Result<()> {
+        // channels inheriting their parent's memberships already use the parent's bans
+        if group.parent_group.is_none() || group.inherit_membership {
+            return Ok(());
+        }
+
+        let parent_ban = parent_ban.ok_or(GenericError::MissingParentBan)?;
+        Self::validate_not_banned(parent_ban)
+    }
+}
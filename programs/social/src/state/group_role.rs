@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::PostGroup;
+
+#[account]
+#[derive(Default)]
+pub struct GroupRole {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the role is for
+    pub group: Pubkey,
+
+    /// profile the role has been assigned to
+    pub profile: Pubkey,
+
+    /// bitflag set of the permissions granted to the `profile` (e.g. `GroupRole::HIDE_POSTS`)
+    pub permissions: u16,
+}
+
+impl GroupRole {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "group_role";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// add, approve, and remove members of the group
+    pub const MANAGE_MEMBERS: u16 = 1 << 0;
+    /// hide and remove posts within the group
+    pub const HIDE_POSTS: u16 = 1 << 1;
+    /// lock threads within the group, so they no longer accept replies
+    pub const LOCK_THREADS: u16 = 1 << 2;
+    /// pin posts within the group
+    pub const PIN: u16 = 1 << 3;
+    /// edit the group's metadata (e.g. `update_post_group` and `rename_post_group`)
+    pub const EDIT_METADATA: u16 = 1 << 4;
+    /// assign and revoke roles within the group
+    pub const ASSIGN_ROLES: u16 = 1 << 5;
+    /// create channels within the group
+    pub const MANAGE_CHANNELS: u16 = 1 << 6;
+    /// resolve reports of posts within the group
+    pub const RESOLVE_REPORTS: u16 = 1 << 7;
+    /// add or override the content labels of posts within the group
+    pub const LABEL_POSTS: u16 = 1 << 8;
+
+    /// every permission that can be granted
+    pub const ALL: u16 = Self::MANAGE_MEMBERS
+        | Self::HIDE_POSTS
+        | Self::LOCK_THREADS
+        | Self::PIN
+        | Self::EDIT_METADATA
+        | Self::ASSIGN_ROLES
+        | Self::MANAGE_CHANNELS
+        | Self::RESOLVE_REPORTS
+        | Self::LABEL_POSTS;
+
+    /// check if the role grants all of the given `permissions`
+    pub fn has(&self, permissions: u16) -> bool {
+        self.permissions & permissions == permissions
+    }
+
+    /// validate the `profile` is allowed to perform an action requiring the given
+    /// `permission` within the `group`.
+    ///
+    /// the group's authority is always allowed, any other profile requires a GroupRole.
+    /// channels inheriting their parent's moderation require the parent's GroupRole
+    pub fn authorize(
+        group: &Account<PostGroup>,
+        profile: &Pubkey,
+        role: Option<&Account<GroupRole>>,
+        permission: u16,
+    ) -> Result<()> {
+        if group.authority == *profile {
+            return Ok(());
+        }
+
+        require!(
+            role.is_some_and(|role| role.group == group.moderation_group(group.key())
+                && role.profile == *profile
+                && role.has(permission)),
+            GenericError::Unauthorized
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct GroupTreasury {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the treasury belongs to
+    pub group: Pubkey,
+
+    /// total lamports ever collected via slashed posting bonds
+    pub total_collected: u64,
+}
+
+impl GroupTreasury {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "group_treasury";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
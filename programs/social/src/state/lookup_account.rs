@@ -10,6 +10,10 @@ pub struct LookupAccount {
 
     /// the account that has the ability to change the the details of the `LookupAccount`
     pub authority: Pubkey,
+
+    /// unix timestamp the username's lease expires at, or `None` when it never expires.
+    /// once expired (and past the grace period), anyone can release the username
+    pub expires_at: Option<i64>,
 }
 
 impl LookupAccount {
@@ -20,4 +24,11 @@ impl LookupAccount {
     pub const SPACE: usize =
         // anchor descriminator + all static variables
         8 + std::mem::size_of::<Self>();
+
+    /// check if the lease has expired and its `grace_period` has ended,
+    /// allowing the username to be released
+    pub fn is_releasable(&self, grace_period: i64, now: i64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| now > expires_at.saturating_add(grace_period))
+    }
 }
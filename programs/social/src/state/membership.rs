@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Membership {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the membership is for
+    pub group: Pubkey,
+
+    /// the member's profile
+    pub profile: Pubkey,
+
+    /// current status of the membership
+    pub status: MembershipStatus,
+}
+
+impl Membership {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "membership";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// check if the membership allows its profile to post
+    pub fn is_active(&self) -> bool {
+        self.status == MembershipStatus::Active
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MembershipStatus {
+    /// the profile has requested to join, pending approval from the group
+    #[default]
+    Pending,
+
+    /// the profile is a member of the group
+    Active,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MembershipMode {
+    /// any profile can post, no Membership is required
+    Open,
+
+    /// only profiles added by the group (via `add_member`) can post
+    #[default]
+    Allowlist,
+
+    /// profiles can request to join (via `request_membership`),
+    /// and can post once approved by the group
+    Approval,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+
+#[account]
+#[derive(Default)]
+pub struct Message {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// message id for deriving the Message's address.
+    /// the current `conversation.message_count` is used then incremented
+    pub message_id: u32,
+
+    /// address of the Conversation this message was sent in
+    pub conversation: Pubkey,
+
+    /// profile that sent the message
+    pub sender: Pubkey,
+
+    /// nonce used when encrypting the `ciphertext`
+    pub nonce: [u8; 24],
+
+    /// encrypted message body, or an encrypted uri to off-chain message content.
+    /// only the two participants of the Conversation are able to decrypt it
+    pub ciphertext: Vec<u8>,
+}
+
+impl Message {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "message";
+
+    /// max allowed length of the `ciphertext` (including the authentication tag)
+    pub const MAX_LEN_CIPHERTEXT: usize = 512;
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>() +
+        // bytes `ciphertext`
+        Self::MAX_LEN_CIPHERTEXT;
+
+    /// validate the encrypted `ciphertext` of a Message
+    pub fn validate_ciphertext(ciphertext: &[u8]) -> Result<()> {
+        require!(!ciphertext.is_empty(), GenericError::InvalidMessage);
+        require!(ciphertext.len() <= Self::MAX_LEN_CIPHERTEXT, GenericError::MessageTooLong);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,45 @@
+mod bond;
+mod config;
+mod conversation;
+mod endorsement;
+mod engagement;
+mod fee_exemption;
+mod group_ban;
+mod group_role;
+mod group_treasury;
 mod lookup_account;
+mod membership;
+mod message;
 mod post;
+mod post_activity;
 mod post_group;
 mod profile;
+mod reaction;
+mod report;
+mod tag;
+mod treasury;
+mod username_reservation;
+mod vouch;
 
+pub use bond::*;
+pub use config::*;
+pub use conversation::*;
+pub use endorsement::*;
+pub use engagement::*;
+pub use fee_exemption::*;
+pub use group_ban::*;
+pub use group_role::*;
+pub use group_treasury::*;
 pub use lookup_account::*;
+pub use membership::*;
+pub use message::*;
 pub use post::*;
+pub use post_activity::*;
 pub use post_group::*;
 pub use profile::*;
+pub use reaction::*;
+pub use report::*;
+pub use tag::*;
+pub use treasury::*;
+pub use username_reservation::*;
+pub use vouch::*;
@@ -17,7 +17,7 @@ pub struct Post {
     /// address of the PostGroup this post was published to
     pub group: Pubkey,
 
-    /// author profile of the originating post
+    /// author profile of the originating post (aka the "primary author")
     pub author: Pubkey,
 
     /// parent post used to denote if a given post has been
@@ -31,6 +31,40 @@ pub struct Post {
     /// uri to an off-chain JSON metadata file that stores the actual post information
     pub metadata_uri: String,
 
+    // note: fields added after the initial release are appended below, so existing
+    // accounts remain readable once migrated via `migrate_post`
+
+    /// additional author profiles that co-signed the creation of the post
+    pub co_authors: Vec<Pubkey>,
+
+    /// which of the post's authors are allowed to edit it
+    pub edit_rule: PostEditRule,
+
+    /// tracks the total number of reports a given post has received.
+    /// note: the `report_count` will be used as a seed for child Reports.
+    /// effecively allowing us to easily enumerate the post's reports
+    pub report_count: u32,
+
+    /// content labels set by the post's author (e.g. `Post::NSFW`)
+    pub labels: u8,
+
+    /// content labels set by the group's moderators, overriding the author's `labels`
+    pub moderator_labels: Option<u8>,
+
+    /// visibility of the post, as set by the group's moderators
+    pub moderation_status: ModerationStatus,
+
+    /// code for the reason the post was moderated (e.g. `Report::SPAM`)
+    pub moderation_reason: u8,
+
+    /// whether the author's posting Bond has already been slashed for this post
+    pub bond_slashed: bool,
+
+    /// locked posts no longer accept replies, as set by the group's moderators
+    pub locked: bool,
+
+    /// pinned posts are displayed above the group's other posts, as set by its moderators
+    pub pinned: bool,
 }
 
 
@@ -38,10 +72,30 @@ impl Post {
     /// static prefix seed string used to derive the PDAs
     pub const PREFIX_SEED : &str = "post";
 
+    /// max number of co-authors a single post can have (excluding the primary `author`)
+    pub const MAX_CO_AUTHORS: usize = 4;
+
+    /// content not safe for work
+    pub const NSFW: u8 = 1 << 0;
+    /// content spoiling a plot (e.g. of a show or game)
+    pub const SPOILER: u8 = 1 << 1;
+    /// graphic or violent content
+    pub const GRAPHIC: u8 = 1 << 2;
+    /// political content
+    pub const POLITICAL: u8 = 1 << 3;
+    /// content generated by AI
+    pub const AI_GENERATED: u8 = 1 << 4;
+
+    /// every content label that can be set
+    pub const ALL_LABELS: u8 =
+        Self::NSFW | Self::SPOILER | Self::GRAPHIC | Self::POLITICAL | Self::AI_GENERATED;
+
     /// total on-chain space needed to allocate the account
     pub const SPACE: usize =
         // anchor descriminator + all static variables
         8 + std::mem::size_of::<Self>() + 
+        // vec `co_authors`
+        Self::MAX_CO_AUTHORS * 32 +
         // string `metadata_uri`
         MAX_LEN_URI;
 
@@ -50,4 +104,50 @@ impl Post {
         require!(uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// validate the given content `labels` only contains known labels
+    pub fn validate_labels(labels: u8) -> Result<()> {
+        require!(labels & !Self::ALL_LABELS == 0, GenericError::InvalidLabels);
+        Ok(())
+    }
+
+    /// content labels clients should apply to the post, preferring the moderators' labels
+    pub fn effective_labels(&self) -> u8 {
+        self.moderator_labels.unwrap_or(self.labels)
+    }
+
+    /// check if the given author profile is allowed to edit the post, based on its `edit_rule`
+    pub fn can_edit(&self, profile: &Pubkey) -> bool {
+        if self.author == *profile {
+            return true;
+        }
+
+        match self.edit_rule {
+            PostEditRule::PrimaryAuthor => false,
+            PostEditRule::AnyAuthor => self.co_authors.contains(profile),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PostEditRule {
+    /// only the primary `author` can edit the post
+    #[default]
+    PrimaryAuthor,
+
+    /// the primary `author` and any of the `co_authors` can edit the post
+    AnyAuthor,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModerationStatus {
+    /// the post is displayed normally
+    #[default]
+    Visible,
+
+    /// the post should be collapsed or hidden by clients, but still be viewable on request
+    Hidden,
+
+    /// the post should not be displayed by clients at all
+    Removed,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+use crate::state::{PostGroup, Profile};
+
+#[account]
+#[derive(Default)]
+pub struct PostActivity {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the PostGroup the activity is tracked within
+    pub group: Pubkey,
+
+    /// profile whose posting activity is tracked
+    pub profile: Pubkey,
+
+    /// slot the profile's allowance was last refilled at (or last full at).
+    /// slots since then that did not add up to a whole post are kept towards the next refill
+    pub last_refill_slot: u64,
+
+    /// posts the profile can still publish before being rate limited
+    pub tokens: u16,
+}
+
+impl PostActivity {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "post_activity";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// record a new post by the `author` within the `group`, enforcing the group's
+    /// `rate_limit` as a token bucket. a token is refilled every `min_interval_slots`,
+    /// up to the `burst` allowance
+    pub fn record(
+        activity: Option<&mut Account<PostActivity>>,
+        bump: u8,
+        group: &Account<PostGroup>,
+        author: &Account<Profile>,
+    ) -> Result<()> {
+        // the group's own authority is never rate limited
+        let rate_limit = match group.rate_limit {
+            Some(rate_limit) if group.authority != author.key() => rate_limit,
+            _ => return Ok(()),
+        };
+
+        let activity = activity.ok_or(GenericError::MissingPostActivityAccount)?;
+        let slot = Clock::get()?.slot;
+
+        if activity.group == Pubkey::default() {
+            // the account was just created by `init_if_needed`, start with a full bucket
+            activity.bump = bump;
+            activity.group = group.key();
+            activity.profile = author.key();
+            activity.tokens = rate_limit.burst;
+            activity.last_refill_slot = slot;
+        } else {
+            // note: `validate_input` ensures the group's `min_interval_slots` is never zero
+            let refilled =
+                slot.saturating_sub(activity.last_refill_slot) / rate_limit.min_interval_slots;
+            let tokens = u64::from(activity.tokens).saturating_add(refilled);
+
+            if tokens >= rate_limit.burst.into() {
+                // a full bucket does not accumulate any more time towards refills
+                activity.tokens = rate_limit.burst;
+                activity.last_refill_slot = slot;
+            } else {
+                // only advance by the refilled tokens, keeping the partial progress
+                activity.tokens = tokens as u16;
+                activity.last_refill_slot += refilled * rate_limit.min_interval_slots;
+            }
+        }
+
+        require!(activity.tokens > 0, GenericError::RateLimited);
+
+        activity.tokens -= 1;
+
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 
+use crate::constants::*;
 use crate::errors::GenericError;
+use crate::state::{Membership, MembershipMode, Profile};
 
 #[account]
 #[derive(Default)]
 pub struct PostGroup {
     /// bump used to derive the PDA
     pub bump: u8,
-    
+
     /// random seed bytes used to derive the PDA for the PostGroup's account
     pub random_seed: [u8; 32],
 
@@ -18,29 +22,320 @@ pub struct PostGroup {
     /// note: the `post_count` will be used as a seed for child Posts.
     /// effecively allowing us to easily enumerate child posts
     pub post_count: u32,
-    
+
     /// simple string used to locate the PostGroup via a friendly name
     pub name: String,
+
+    // note: fields added after the initial release are appended below, so existing
+    // accounts remain readable once migrated via `migrate_post_group`
+
+    /// profile the PostGroup is being transferred to, pending its acceptance
+    pub pending_authority: Option<Pubkey>,
+
+    /// parent PostGroup when this PostGroup is a channel within it
+    pub parent_group: Option<Pubkey>,
+
+    /// counter for total number of channels within the PostGroup.
+    /// note: the `channel_count` is used to derive each channel's `random_seed`.
+    /// effecively allowing us to easily enumerate the channels
+    pub channel_count: u32,
+
+    /// when a channel, use the parent's memberships and bans instead of its own
+    pub inherit_membership: bool,
+
+    /// when a channel, use the parent's moderator roles instead of its own
+    pub inherit_moderation: bool,
+
+    /// archived PostGroups are read-only, no new posts or replies are allowed
+    pub archived: bool,
+
+    /// minimum `Profile.reputation` required to post within the PostGroup.
+    pub min_reputation: u64,
+
+    /// who is allowed to publish root posts within the PostGroup
+    pub membership_mode: MembershipMode,
+
+    /// how often a single profile is allowed to post (or reply) within the PostGroup
+    pub rate_limit: Option<RateLimit>,
+
+    /// refundable lamport bond a profile must deposit before posting within the PostGroup
+    pub posting_bond: Option<PostingBond>,
+
+    /// SPL token the author's authority must hold to post within the PostGroup
+    pub token_gate: Option<TokenGate>,
+
+    /// verified NFT collection the author's authority must hold an NFT from to post
+    /// within the PostGroup
+    pub collection_gate: Option<Pubkey>,
+
+    /// client defined code used to categorize the PostGroup (e.g. gaming, finance, etc)
+    pub category: u16,
+
+    /// hash of the off-chain JSON metadata file, allowing clients to verify its content
+    pub content_hash: [u8; 32],
+
+    /// short description of the PostGroup (e.g. its purpose and rules)
+    pub description: String,
+
+    /// uri to an off-chain image to be displayed for the PostGroup
+    pub image_uri: String,
+
+    /// uri to an off-chain JSON metadata file for additional PostGroup information
+    pub metadata_uri: String,
 }
 
 impl PostGroup {
     /// static prefix seed string used to derive the PDAs
     pub const PREFIX_SEED: &str = "post_group";
 
+    /// static prefix seed string used to derive the PDAs of channels
+    pub const CHANNEL_PREFIX_SEED: &str = "channel";
+
     /// max allowed length of the friendly name
     pub const MAX_LEN_NAME : usize = 32;
 
+    /// max allowed length of the description
+    pub const MAX_LEN_DESCRIPTION: usize = 256;
+
     /// total on-chain space needed to allocate the account
     pub const SPACE: usize =
         // anchor descriminator + all static variables
         8 + std::mem::size_of::<Self>() + 
         // string `name`
-        Self::MAX_LEN_NAME;
+        Self::MAX_LEN_NAME +
+        // string `description`
+        Self::MAX_LEN_DESCRIPTION +
+        // string `image_uri`
+        MAX_LEN_URI +
+        // string `metadata_uri`
+        MAX_LEN_URI;
+
+    /// prefix seed used to derive the PostGroup's PDA, which differs for channels
+    pub fn prefix_seed(&self) -> &'static [u8] {
+        match self.parent_group {
+            Some(_) => Self::CHANNEL_PREFIX_SEED.as_bytes(),
+            None => Self::PREFIX_SEED.as_bytes(),
+        }
+    }
+
+    /// derive the `random_seed` of a parent's channel from the given `channel_id`
+    pub fn channel_seed(parent_group: &Pubkey, channel_id: u32) -> Result<[u8; 32]> {
+        let seed = Pubkey::create_with_seed(parent_group, &channel_id.to_string(), &crate::ID)
+            .map_err(|_| GenericError::InvalidChannel)?;
+
+        Ok(seed.to_bytes())
+    }
+
+    /// refresh the authority of a channel `group` from its `parent_group`.
+    ///
+    /// note: a channel's stored authority is only a cache of its parent's authority, so
+    /// transferring the parent also transfers all of its channels
+    pub fn resolve_authority(
+        group: &mut Account<PostGroup>,
+        parent_group: Option<&Account<PostGroup>>,
+    ) -> Result<()> {
+        if let Some(parent_key) = group.parent_group {
+            let parent_group = parent_group.ok_or(GenericError::MissingParentGroup)?;
+            require_keys_eq!(parent_group.key(), parent_key, GenericError::InvalidAccount);
+
+            group.authority = parent_group.authority;
+        }
+
+        Ok(())
+    }
+
+    /// address of the PostGroup whose memberships and bans apply to the `group`
+    pub fn membership_group(&self, group: Pubkey) -> Pubkey {
+        match self.parent_group {
+            Some(parent_group) if self.inherit_membership => parent_group,
+            _ => group,
+        }
+    }
+
+    /// address of the PostGroup whose moderator roles apply to the `group`
+    pub fn moderation_group(&self, group: Pubkey) -> Pubkey {
+        match self.parent_group {
+            Some(parent_group) if self.inherit_moderation => parent_group,
+            _ => group,
+        }
+    }
+
+    /// validate the standard generic input
+    pub fn validate_input(input: &PostGroup) -> Result<()> {
+        require!(
+            input.description.len() <= Self::MAX_LEN_DESCRIPTION,
+            GenericError::DescriptionTooLong
+        );
+        require!(input.image_uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
+        require!(input.metadata_uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
+
+        Self::validate_name(&input.name)?;
+
+        // a rate limit without any allowance (or refill) would block all posting forever
+        if let Some(rate_limit) = input.rate_limit {
+            require!(
+                rate_limit.burst > 0 && rate_limit.min_interval_slots > 0,
+                GenericError::InvalidRateLimit
+            );
+        }
+
+        Self::validate_token_gate_input(input.token_gate)?;
+
+        Ok(())
+    }
+
+    /// validate a `token_gate` requires holding some of its token
+    pub fn validate_token_gate_input(token_gate: Option<TokenGate>) -> Result<()> {
+        // a gate requiring zero tokens would allow anyone to post
+        if let Some(token_gate) = token_gate {
+            require!(token_gate.min_amount > 0, GenericError::InvalidTokenGate);
+        }
+
+        Ok(())
+    }
 
     /// validate the friendly `name` of a PostGroup
     pub fn validate_name(name: &String) -> Result<()>{
         require!(name.len() <= Self::MAX_LEN_NAME, GenericError::NameTooLong);
+        // an empty name denotes a closed group whose name was released
+        require!(!name.is_empty(), GenericError::EmptyName);
 
         Ok(())
     }
+
+    /// validate the posting rules of the PostGroup allow the `author` to post
+    pub fn validate_author(&self, author: &Account<Profile>) -> Result<()> {
+        // nobody is allowed to post within an archived group
+        require!(!self.archived, GenericError::GroupArchived);
+
+        // the group's own authority is always allowed to post
+        if self.authority == author.key() {
+            return Ok(());
+        }
+
+        require!(
+            author.reputation >= self.min_reputation,
+            GenericError::InsufficientReputation
+        );
+
+        Ok(())
+    }
+
+    /// validate the `author` is allowed to publish root posts within the PostGroup,
+    /// based on its `membership_mode`
+    pub fn validate_member(
+        &self,
+        author: &Account<Profile>,
+        membership: Option<&Account<Membership>>,
+    ) -> Result<()> {
+        // the group's own authority is always allowed to post
+        if self.authority == author.key() || self.membership_mode == MembershipMode::Open {
+            return Ok(());
+        }
+
+        require!(
+            membership.is_some_and(|membership| membership.is_active()),
+            GenericError::NotAMember
+        );
+
+        Ok(())
+    }
+
+    /// validate the `author`'s authority holds enough of the group's gating token,
+    /// when the PostGroup has a `token_gate`
+    pub fn validate_token_gate(
+        &self,
+        author: &Account<Profile>,
+        token_account: Option<&Account<TokenAccount>>,
+    ) -> Result<()> {
+        // the group's own authority is always allowed to post
+        if self.authority == author.key() {
+            return Ok(());
+        }
+
+        let token_gate = match self.token_gate {
+            Some(token_gate) => token_gate,
+            None => return Ok(()),
+        };
+
+        let token_account = token_account.ok_or(GenericError::MissingTokenAccount)?;
+
+        // the tokens must be held by the author's authority, not just anyone
+        require_keys_eq!(token_account.mint, token_gate.mint, GenericError::InvalidAccount);
+        require_keys_eq!(token_account.owner, author.authority, GenericError::Unauthorized);
+        require!(
+            token_account.amount >= token_gate.min_amount,
+            GenericError::InsufficientTokenBalance
+        );
+
+        Ok(())
+    }
+
+    /// validate the `author`'s authority holds an NFT from the group's verified collection,
+    /// when the PostGroup has a `collection_gate`
+    pub fn validate_collection_gate(
+        &self,
+        author: &Account<Profile>,
+        token_account: Option<&Account<TokenAccount>>,
+        metadata: Option<&Account<MetadataAccount>>,
+    ) -> Result<()> {
+        // the group's own authority is always allowed to post
+        if self.authority == author.key() {
+            return Ok(());
+        }
+
+        let collection = match self.collection_gate {
+            Some(collection) => collection,
+            None => return Ok(()),
+        };
+
+        let token_account = token_account.ok_or(GenericError::MissingTokenAccount)?;
+        let metadata = metadata.ok_or(GenericError::MissingMetadataAccount)?;
+
+        // the NFT must be held by the author's authority, not just anyone
+        require_keys_eq!(token_account.mint, metadata.mint, GenericError::InvalidAccount);
+        require_keys_eq!(token_account.owner, author.authority, GenericError::Unauthorized);
+        require!(token_account.amount >= 1, GenericError::InsufficientTokenBalance);
+
+        // only the collection's update authority is able to verify an NFT is part of it
+        require!(
+            metadata
+                .collection
+                .as_ref()
+                .is_some_and(|nft| nft.verified && nft.key == collection),
+            GenericError::NotInCollection
+        );
+
+        Ok(())
+    }
+}
+
+/// SPL token holding requirement for posting within a PostGroup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TokenGate {
+    /// mint of the token that must be held
+    pub mint: Pubkey,
+
+    /// minimum amount of the token (in base units) that must be held
+    pub min_amount: u64,
+}
+
+/// posting rate limit applied to each profile within a PostGroup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    /// slots it takes a profile to regain the allowance for a single post
+    pub min_interval_slots: u64,
+
+    /// max number of posts a profile can publish in quick succession
+    pub burst: u16,
+}
+
+/// refundable anti-spam bond required to post within a PostGroup
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PostingBond {
+    /// lamports each profile must deposit
+    pub amount: u64,
+
+    /// slots a profile must wait after requesting to withdraw its bond
+    pub cooldown_slots: u64,
 }
@@ -14,7 +14,9 @@ pub struct Profile {
     /// owner with blanket authority over the Profile
     pub authority: Pubkey,
 
-    /// display name to be used for the Profile
+    /// unique username of the Profile, reserved via its LookupAccount.
+    /// empty once an expired username is released, in which case clients
+    /// should fall back to displaying the Profile's address
     pub username: String,
 
     /// display name to be used for the Profile
@@ -25,6 +27,15 @@ pub struct Profile {
 
     /// uri to an off-chain JSON metadata file for additional profile information
     pub metadata_uri: String,
+
+    /// x25519 public key other profiles use to encrypt direct messages to this Profile
+    pub messaging_key: Option<[u8; 32]>,
+
+    /// total number of other profiles with at least one active Vouch for this Profile
+    pub endorsement_count: u32,
+
+    /// reputation earned from other profiles engaging with this Profile's posts
+    pub reputation: u64,
 }
 
 impl Profile {
@@ -46,11 +57,18 @@ impl Profile {
 
     /// validate the standard generic input
     pub fn validate_input(input: &Profile) -> Result<()>{
+        Self::validate_details(input)?;
+        Self::validate_username(&input.username)?;
+
+        Ok(())
+    }
+
+    /// validate the input details that can be changed via `update_profile`
+    /// (i.e. everything except the username)
+    pub fn validate_details(input: &Profile) -> Result<()>{
         require!(input.name.len() <= MAX_LEN_NAME, GenericError::NameTooLong);
         require!(input.metadata_uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
         require!(input.image_uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
-    
-        Self::validate_username(&input.username)?;
 
         Ok(())
     }
@@ -58,6 +76,8 @@ impl Profile {
     /// validate the standard generic input
     pub fn validate_username(username: &String) -> Result<()>{
         require!(username.len() <= MAX_LEN_USERNAME, GenericError::NameTooLong);
+        // an empty username denotes a profile whose username was released
+        require!(!username.is_empty(), GenericError::EmptyUsername);
         
         // todo: validate username for a set character set [a-z0-9_-]
 
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Reaction {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// address of the Post that was reacted to
+    pub post: Pubkey,
+
+    /// profile that reacted to the post
+    pub profile: Pubkey,
+
+    /// client defined code for the kind of reaction (e.g. like, upvote, emoji)
+    pub kind: u8,
+}
+
+impl Reaction {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "reaction";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GenericError;
+
+#[account]
+#[derive(Default)]
+pub struct Report {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// report id for deriving the Report's address.
+    /// the current `post.report_count` is used then incremented
+    pub report_id: u32,
+
+    /// address of the reported Post
+    pub post: Pubkey,
+
+    /// profile that reported the post
+    pub reporter: Pubkey,
+
+    /// code for the reason the post was reported (e.g. `Report::SPAM`)
+    pub reason: u8,
+
+    /// outcome recorded by the moderator that resolved the report
+    pub outcome: ReportOutcome,
+
+    /// profile of the group authority or moderator that resolved the report
+    pub resolved_by: Option<Pubkey>,
+
+    /// uri to off-chain evidence supporting the report (e.g. screenshots)
+    pub evidence_uri: Option<String>,
+}
+
+impl Report {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "report";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>() +
+        // string `evidence_uri`
+        MAX_LEN_URI;
+
+    /// unsolicited or repetitive content
+    pub const SPAM: u8 = 0;
+    /// harassment or hate speech
+    pub const HARASSMENT: u8 = 1;
+    /// explicit content that was not labeled as such
+    pub const EXPLICIT: u8 = 2;
+    /// illegal content
+    pub const ILLEGAL: u8 = 3;
+    /// any other reason, detailed via the `evidence_uri`
+    pub const OTHER: u8 = u8::MAX;
+
+    /// validate the optional `evidence_uri`
+    pub fn validate_evidence_uri(uri: Option<&str>) -> Result<()> {
+        if let Some(uri) = uri {
+            require!(uri.len() <= MAX_LEN_URI, GenericError::UriTooLong);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReportOutcome {
+    /// the report has not been resolved yet
+    #[default]
+    Pending,
+
+    /// the report was reviewed and no action was needed
+    Dismissed,
+
+    /// the report was reviewed and action was taken against the post
+    Upheld,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GenericError;
+
+#[account]
+#[derive(Default)]
+pub struct Tag {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// counter for total number of posts tagged with this Tag.
+    /// note: the `post_count` will be used as a seed for child TagEntries.
+    /// effecively allowing us to easily enumerate the tagged posts
+    pub post_count: u32,
+
+    /// normalized hashtag (without the leading `#`) used to derive the PDA
+    pub name: String,
+}
+
+impl Tag {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "tag";
+
+    /// max allowed length of a normalized tag name
+    pub const MAX_LEN_NAME: usize = 32;
+
+    /// max number of tags that can be provided when creating a single post
+    pub const MAX_TAGS_PER_POST: usize = 5;
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>() +
+        // string `name`
+        Self::MAX_LEN_NAME;
+
+    /// normalize a user provided hashtag into the form used to derive the PDA.
+    ///
+    /// the leading `#` is dropped and the tag is lowercased. the result must only
+    /// contain the characters `[a-z0-9_]`
+    pub fn normalize(tag: &str) -> Result<String> {
+        let name = tag.strip_prefix('#').unwrap_or(tag).to_ascii_lowercase();
+
+        require!(!name.is_empty(), GenericError::InvalidTag);
+        require!(name.len() <= Self::MAX_LEN_NAME, GenericError::NameTooLong);
+        require!(
+            name.bytes()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_'),
+            GenericError::InvalidTag
+        );
+
+        Ok(name)
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct TagEntry {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// entry id for deriving the TagEntry's address.
+    /// the current `tag.post_count` is used then incremented
+    pub entry_id: u32,
+
+    /// address of the Tag this entry belongs to
+    pub tag: Pubkey,
+
+    /// address of the tagged Post
+    pub post: Pubkey,
+}
+
+impl TagEntry {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "tag_entry";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::GenericError;
+use crate::state::FeeExemption;
+
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// total lamports ever collected via protocol fees
+    pub total_collected: u64,
+}
+
+impl Treasury {
+    /// static prefix seed string used to derive the PDA
+    pub const PREFIX_SEED: &str = "treasury";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+
+    /// transfer a protocol `fee` from the `payer` into the treasury,
+    /// unless the payer has a FeeExemption
+    ///
+    /// note: the treasury is only required when a fee is actually charged, so instructions
+    /// without a fee do not write lock the (global) treasury account
+    pub fn collect_fee<'info>(
+        treasury: Option<&mut Account<'info, Treasury>>,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        fee: u64,
+        fee_exemption: Option<&Account<FeeExemption>>,
+    ) -> Result<()> {
+        if fee == 0 || fee_exemption.is_some() {
+            return Ok(());
+        }
+
+        let treasury = treasury.ok_or(GenericError::MissingTreasuryAccount)?;
+
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+
+        treasury.total_collected = treasury.total_collected.saturating_add(fee);
+
+        Ok(())
+    }
+}
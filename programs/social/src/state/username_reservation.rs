@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GenericError;
+use crate::state::Config;
+use crate::utils::verified_ed25519_messages;
+
+#[account]
+#[derive(Default)]
+pub struct UsernameReservation {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// username that can only be claimed with a voucher signed by the `config.reservation_key`
+    pub username: String,
+}
+
+impl UsernameReservation {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "username_reservation";
+
+    /// static prefix of every claim voucher's signed message
+    pub const VOUCHER_PREFIX: &str = "claim_username";
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>() +
+        // string `username`
+        MAX_LEN_USERNAME;
+
+    /// build the message the reservation key signs to let the `claimant` claim a `username`
+    /// until the `expires_slot` (inclusive)
+    ///
+    /// note: the `claimant` is the authority of the profile claiming the username. the message
+    /// is bound to this program and its `config` address, so a voucher can not be replayed
+    /// against another deployment that uses the same reservation key
+    pub fn voucher_message(
+        config: &Pubkey,
+        claimant: &Pubkey,
+        expires_slot: u64,
+        username: &str,
+    ) -> Vec<u8> {
+        [
+            Self::VOUCHER_PREFIX.as_bytes(),
+            crate::ID.as_ref(),
+            config.as_ref(),
+            claimant.as_ref(),
+            &expires_slot.to_le_bytes(),
+            username.as_bytes(),
+        ]
+        .concat()
+    }
+
+    /// get the expiry slot of a signed voucher `message`, when it is a voucher for the
+    /// `claimant` to claim the `username` via the `config`
+    fn voucher_expiry(
+        message: &[u8],
+        config: &Pubkey,
+        claimant: &Pubkey,
+        username: &str,
+    ) -> Option<u64> {
+        // the program id, config, and claimant addresses precede the expiry slot
+        let offset = Self::VOUCHER_PREFIX.len() + 32 * 3;
+        let expires_slot = u64::from_le_bytes(message.get(offset..offset + 8)?.try_into().ok()?);
+
+        (message == Self::voucher_message(config, claimant, expires_slot, username).as_slice())
+            .then_some(expires_slot)
+    }
+
+    /// validate the `claimant` may register a `username`, given the username's (possibly
+    /// uninitialized) UsernameReservation PDA. the caller is responsible for verifying the
+    /// PDA's address
+    ///
+    /// reserved usernames require an ed25519 signature verification instruction (signed by the
+    /// `config.reservation_key`) to precede the current instruction within the transaction.
+    /// vouchers expire, so a voucher can not be replayed to reclaim a username after the
+    /// reservation key intended it to be claimed
+    pub fn validate_claim(
+        reservation: &AccountInfo,
+        config: &Account<Config>,
+        instructions_sysvar: Option<&AccountInfo>,
+        claimant: &Pubkey,
+        username: &str,
+    ) -> Result<()> {
+        // the username has never been reserved
+        if reservation.owner != &crate::ID || reservation.data_is_empty() {
+            return Ok(());
+        }
+
+        let reservation_key = config.reservation_key.ok_or(GenericError::InvalidVoucher)?;
+        let instructions_sysvar = instructions_sysvar.ok_or(GenericError::MissingVoucher)?;
+
+        let expires_slot = verified_ed25519_messages(instructions_sysvar, &reservation_key)?
+            .iter()
+            .find_map(|message| Self::voucher_expiry(message, &config.key(), claimant, username))
+            .ok_or(GenericError::InvalidVoucher)?;
+        require!(Clock::get()?.slot <= expires_slot, GenericError::VoucherExpired);
+
+        Ok(())
+    }
+}
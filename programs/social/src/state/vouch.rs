@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct Vouch {
+    /// bump used to derive the PDA
+    pub bump: u8,
+
+    /// profile giving the endorsement
+    pub endorser: Pubkey,
+
+    /// profile receiving the endorsement
+    pub endorsee: Pubkey,
+
+    /// skill or topic code the endorsement is for.
+    /// note: `Vouch::GENERAL` is used when the endorsement is not for a specific skill/topic
+    pub skill: u16,
+}
+
+impl Vouch {
+    /// static prefix seed string used to derive the PDAs
+    pub const PREFIX_SEED: &str = "vouch";
+
+    /// skill code used for a general endorsement of a profile
+    pub const GENERAL: u16 = 0;
+
+    /// total on-chain space needed to allocate the account
+    pub const SPACE: usize =
+        // anchor descriminator + all static variables
+        8 + std::mem::size_of::<Self>();
+}
@@ -0,0 +1,302 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program;
+
+use crate::errors::GenericError;
+use crate::state::{Post, Profile, Tag, TagEntry};
+
+/// Create a program owned PDA account that could not be declared via `#[account(init)]`
+/// (e.g. accounts provided via `remaining_accounts`)
+///
+/// note: like anchor's `init` constraint, this will still succeed when someone has already
+/// transferred lamports to the PDA's address
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = new_account.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // top up the account to be rent exempt
+    let required_lamports = rent.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate {
+                account_to_allocate: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign {
+                account_to_assign: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// Grow a program owned `account` created by an earlier version of the program to `space`,
+/// topping it up to remain rent exempt. the account must start with the `discriminator`
+///
+/// note: the new bytes are zeroed, which deserialize to the default value of any fields
+/// appended to the account since it was created
+pub fn migrate_legacy_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    discriminator: &[u8],
+    space: usize,
+) -> Result<()> {
+    require!(
+        account.data_len() < space && account.try_borrow_data()?.starts_with(discriminator),
+        GenericError::InvalidAccount
+    );
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let required_lamports = rent.saturating_sub(account.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+/// Index a newly created Post under each of the provided `tags`
+///
+/// for each tag, the `remaining_accounts` must contain the Tag PDA followed by the
+/// TagEntry PDA for the tag's current `post_count`. Tags are created on first use
+pub fn index_post_tags<'info>(
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    post: Pubkey,
+    tags: &[String],
+) -> Result<()> {
+    require!(tags.len() <= Tag::MAX_TAGS_PER_POST, GenericError::TooManyTags);
+    require!(
+        remaining_accounts.len() >= tags.len() * 2,
+        ErrorCode::AccountNotEnoughKeys
+    );
+
+    let mut indexed: Vec<String> = Vec::with_capacity(tags.len());
+
+    for (tag_input, accounts) in tags.iter().zip(remaining_accounts.chunks(2)) {
+        let name = Tag::normalize(tag_input)?;
+
+        // each tag may only be applied once per post
+        require!(!indexed.contains(&name), GenericError::DuplicateTag);
+
+        let (tag_info, entry_info) = (&accounts[0], &accounts[1]);
+
+        // locate or create the tag itself
+        let (tag_address, tag_bump) =
+            Pubkey::find_program_address(&[Tag::PREFIX_SEED.as_ref(), name.as_ref()], &crate::ID);
+        require_keys_eq!(tag_info.key(), tag_address, GenericError::InvalidAccount);
+
+        let mut tag = if tag_info.owner == &crate::ID {
+            Tag::try_deserialize(&mut &tag_info.try_borrow_data()?[..])?
+        } else {
+            create_pda_account(
+                payer,
+                tag_info,
+                system_program,
+                Tag::SPACE,
+                &[Tag::PREFIX_SEED.as_ref(), name.as_ref(), &[tag_bump]],
+            )?;
+
+            Tag {
+                bump: tag_bump,
+                name: name.clone(),
+                post_count: 0,
+            }
+        };
+
+        // the current `post_count` is intentionally used here
+        let entry_id = tag.post_count.to_string();
+        let (entry_address, entry_bump) = Pubkey::find_program_address(
+            &[
+                TagEntry::PREFIX_SEED.as_ref(),
+                tag_address.as_ref(),
+                entry_id.as_bytes(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(entry_info.key(), entry_address, GenericError::InvalidAccount);
+
+        create_pda_account(
+            payer,
+            entry_info,
+            system_program,
+            TagEntry::SPACE,
+            &[
+                TagEntry::PREFIX_SEED.as_ref(),
+                tag_address.as_ref(),
+                entry_id.as_bytes(),
+                &[entry_bump],
+            ],
+        )?;
+
+        TagEntry {
+            bump: entry_bump,
+            entry_id: tag.post_count,
+            tag: tag_address,
+            post,
+        }
+        .try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+
+        // auto increment the `tag.post_count` for the next entry to use
+        tag.post_count = tag
+            .post_count
+            .checked_add(1)
+            .ok_or(GenericError::Overflow)?;
+        tag.try_serialize(&mut &mut tag_info.try_borrow_mut_data()?[..])?;
+
+        indexed.push(name);
+    }
+
+    Ok(())
+}
+
+/// Collect the co-author profiles of a new Post from the `remaining_accounts`
+///
+/// each co-author must be provided as its Profile followed by the profile's `authority`,
+/// which must also sign the transaction
+pub fn collect_co_authors(remaining_accounts: &[AccountInfo], author: &Pubkey) -> Result<Vec<Pubkey>> {
+    let co_author_accounts = remaining_accounts.chunks_exact(2);
+    require!(
+        co_author_accounts.remainder().is_empty(),
+        ErrorCode::AccountNotEnoughKeys
+    );
+    require!(
+        remaining_accounts.len() / 2 <= Post::MAX_CO_AUTHORS,
+        GenericError::TooManyCoAuthors
+    );
+
+    let mut co_authors: Vec<Pubkey> = Vec::with_capacity(remaining_accounts.len() / 2);
+
+    for accounts in co_author_accounts {
+        let (profile_info, authority_info) = (&accounts[0], &accounts[1]);
+
+        require_keys_eq!(*profile_info.owner, crate::ID, GenericError::InvalidAccount);
+        let profile = Profile::try_deserialize(&mut &profile_info.try_borrow_data()?[..])?;
+
+        // ensure each co-author is actually approving this
+        require!(authority_info.is_signer, GenericError::Unauthorized);
+        require_keys_eq!(authority_info.key(), profile.authority, GenericError::Unauthorized);
+
+        // each author can only be listed once
+        require!(
+            profile_info.key() != *author && !co_authors.contains(&profile_info.key()),
+            GenericError::DuplicateCoAuthor
+        );
+
+        co_authors.push(profile_info.key());
+    }
+
+    Ok(co_authors)
+}
+
+/// Collect the messages whose signature by the `signer` was verified by an ed25519 signature
+/// verification instruction preceding the current instruction within the transaction
+///
+/// note: the ed25519 program itself verifies the signature, failing the whole transaction when
+/// it is invalid. this only locates the messages verified for the expected signer
+pub fn verified_ed25519_messages(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+) -> Result<Vec<Vec<u8>>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut messages = Vec::new();
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(usize::from(index), instructions_sysvar)?;
+
+        if instruction.program_id == ed25519_program::ID {
+            if let Some(message) = ed25519_instruction_message(&instruction.data, signer) {
+                messages.push(message.to_vec());
+            }
+        }
+    }
+
+    Ok(messages)
+}
+
+/// Get the message of an ed25519 program instruction's `data` that verifies a single signature
+/// by the `signer`
+fn ed25519_instruction_message<'a>(data: &'a [u8], signer: &Pubkey) -> Option<&'a [u8]> {
+    // a single signature, followed by a padding byte and the signature's offsets
+    if data.len() < 16 || data[0] != 1 {
+        return None;
+    }
+
+    let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+
+    // the signature, public key, and message must all be within this instruction's data,
+    // which is denoted by an instruction index of `u16::MAX`
+    let instruction_indexes = [read_u16(4), read_u16(8), read_u16(14)];
+    if instruction_indexes.iter().any(|index| *index != usize::from(u16::MAX)) {
+        return None;
+    }
+
+    let public_key_offset = read_u16(6);
+    let (message_offset, message_size) = (read_u16(10), read_u16(12));
+
+    if data.get(public_key_offset..public_key_offset + 32) != Some(signer.as_ref()) {
+        return None;
+    }
+
+    data.get(message_offset..message_offset + message_size)
+}
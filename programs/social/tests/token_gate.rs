@@ -0,0 +1,411 @@
+//! token gated posting, with the gating tokens minted locally by the spl-token program
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    entrypoint::ProgramResult, program_pack::Pack, system_instruction,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::{Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+use social::errors::GenericError;
+use social::state::{
+    Config, GroupBan, MembershipMode, Post, PostEditRule, PostGroup, Profile, TokenGate,
+};
+
+/// minimum amount of the gating token required to post
+const MIN_AMOUNT: u64 = 10;
+
+/// anchor's `entry` requires the accounts to outlive the instruction,
+/// which the program test processor does not guarantee
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    social::entry(program_id, accounts, data)
+}
+
+/// serialize an anchor `account` into a rent exempt account owned by the program
+fn program_account<T: AccountSerialize>(account: &T, space: usize) -> SolanaAccount {
+    let mut data = vec![0; space];
+    account.try_serialize(&mut &mut data[..]).unwrap();
+
+    SolanaAccount {
+        lamports: Rent::default().minimum_balance(space),
+        data,
+        owner: social::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+struct TestGroup {
+    context: ProgramTestContext,
+    config: Pubkey,
+    group: Pubkey,
+    group_authority: Keypair,
+    group_owner: Pubkey,
+    author_authority: Keypair,
+    author: Pubkey,
+    mint: Keypair,
+}
+
+/// derive a Profile owned by the `authority`, returning its address and account
+fn test_profile(authority: &Pubkey, random_seed: [u8; 32]) -> (Pubkey, SolanaAccount) {
+    let (address, bump) = Pubkey::find_program_address(
+        &[Profile::PREFIX_SEED.as_ref(), random_seed.as_ref()],
+        &social::ID,
+    );
+    let profile = Profile {
+        bump,
+        random_seed,
+        authority: *authority,
+        username: "author".to_string(),
+        ..Default::default()
+    };
+
+    (address, program_account(&profile, Profile::SPACE))
+}
+
+/// start a test validator with an open PostGroup (owned by another profile)
+/// that requires `MIN_AMOUNT` of a locally minted token to post
+async fn setup() -> TestGroup {
+    let mut program_test = ProgramTest::new("social", social::ID, processor!(process_instruction));
+    let mint = Keypair::new();
+
+    let (config, config_bump) =
+        Pubkey::find_program_address(&[Config::PREFIX_SEED.as_ref()], &social::ID);
+    program_test.add_account(
+        config,
+        program_account(
+            &Config {
+                bump: config_bump,
+                max_tags_per_post: 5,
+                ..Default::default()
+            },
+            Config::SPACE,
+        ),
+    );
+
+    let group_authority = Keypair::new();
+    let (group_owner, group_owner_account) = test_profile(&group_authority.pubkey(), [1; 32]);
+    program_test.add_account(group_owner, group_owner_account);
+
+    let author_authority = Keypair::new();
+    let (author, author_account) = test_profile(&author_authority.pubkey(), [2; 32]);
+    program_test.add_account(author, author_account);
+
+    let random_seed = [3; 32];
+    let (group, group_bump) = Pubkey::find_program_address(
+        &[PostGroup::PREFIX_SEED.as_ref(), random_seed.as_ref()],
+        &social::ID,
+    );
+    program_test.add_account(
+        group,
+        program_account(
+            &PostGroup {
+                bump: group_bump,
+                random_seed,
+                authority: group_owner,
+                name: "gated".to_string(),
+                membership_mode: MembershipMode::Open,
+                token_gate: Some(TokenGate {
+                    mint: mint.pubkey(),
+                    min_amount: MIN_AMOUNT,
+                }),
+                ..Default::default()
+            },
+            PostGroup::SPACE,
+        ),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    create_mint(&mut context, &mint).await;
+
+    TestGroup {
+        context,
+        config,
+        group,
+        group_authority,
+        group_owner,
+        author_authority,
+        author,
+        mint,
+    }
+}
+
+/// create a new token `mint` (with the context's payer as its mint authority)
+async fn create_mint(context: &mut ProgramTestContext, mint: &Keypair) {
+    let payer = &context.payer;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                0,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+        &[payer, mint],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+/// create a token account of the `mint` owned by `owner`, minting `amount` tokens into it
+async fn mint_tokens(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let payer = &context.payer;
+    let token_account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &token_account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account(
+                &spl_token::ID,
+                &token_account.pubkey(),
+                mint,
+                owner,
+            )
+            .unwrap(),
+            spl_token::instruction::mint_to(
+                &spl_token::ID,
+                mint,
+                &token_account.pubkey(),
+                &payer.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap(),
+        ],
+        Some(&payer.pubkey()),
+        &[payer, &token_account],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    token_account.pubkey()
+}
+
+/// publish the group's first post as the author, proving ownership via `gate_token_account`
+async fn create_post(
+    test_group: &mut TestGroup,
+    gate_token_account: Pubkey,
+) -> std::result::Result<(), BanksClientError> {
+    let context = &mut test_group.context;
+
+    let (post, _) = Pubkey::find_program_address(
+        &[
+            Post::PREFIX_SEED.as_ref(),
+            test_group.group.as_ref(),
+            "0".as_bytes(),
+        ],
+        &social::ID,
+    );
+    let (ban, _) = Pubkey::find_program_address(
+        &[
+            GroupBan::PREFIX_SEED.as_ref(),
+            test_group.group.as_ref(),
+            test_group.author.as_ref(),
+        ],
+        &social::ID,
+    );
+
+    let instruction = Instruction {
+        program_id: social::ID,
+        accounts: social::accounts::CreatePost {
+            config: test_group.config,
+            system_program: System::id(),
+            payer: context.payer.pubkey(),
+            authority: test_group.author_authority.pubkey(),
+            author: test_group.author,
+            post,
+            group: test_group.group,
+            parent_group: None,
+            membership: None,
+            ban,
+            parent_ban: None,
+            activity: None,
+            bond: None,
+            gate_token_account: Some(gate_token_account),
+            nft_token_account: None,
+            nft_metadata: None,
+            treasury: None,
+            fee_exemption: None,
+        }
+        .to_account_metas(None),
+        data: social::instruction::CreatePost {
+            metadata_uri: "gated post".to_string(),
+            tags: vec![],
+            edit_rule: PostEditRule::PrimaryAuthor,
+            labels: 0,
+        }
+        .data(),
+    };
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &test_group.author_authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(transaction).await
+}
+
+/// assert the `result` failed with the program's `error`
+fn assert_error(result: std::result::Result<(), BanksClientError>, error: GenericError) {
+    let code = ERROR_CODE_OFFSET + error as u32;
+
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(0, InstructionError::Custom(actual)) => {
+            assert_eq!(actual, code, "expected the error {error:?}")
+        }
+        actual => panic!("expected the error {error:?}, got {actual:?}"),
+    }
+}
+
+#[tokio::test]
+async fn create_post_below_min_amount() {
+    let mut test_group = setup().await;
+    let (mint, owner) = (
+        test_group.mint.pubkey(),
+        test_group.author_authority.pubkey(),
+    );
+    let token_account = mint_tokens(&mut test_group.context, &mint, &owner, MIN_AMOUNT - 1).await;
+
+    let result = create_post(&mut test_group, token_account).await;
+    assert_error(result, GenericError::InsufficientTokenBalance);
+}
+
+#[tokio::test]
+async fn create_post_at_min_amount() {
+    let mut test_group = setup().await;
+    let (mint, owner) = (
+        test_group.mint.pubkey(),
+        test_group.author_authority.pubkey(),
+    );
+    let token_account = mint_tokens(&mut test_group.context, &mint, &owner, MIN_AMOUNT).await;
+
+    create_post(&mut test_group, token_account).await.unwrap();
+}
+
+#[tokio::test]
+async fn create_post_above_min_amount() {
+    let mut test_group = setup().await;
+    let (mint, owner) = (
+        test_group.mint.pubkey(),
+        test_group.author_authority.pubkey(),
+    );
+    let token_account = mint_tokens(&mut test_group.context, &mint, &owner, MIN_AMOUNT * 2).await;
+
+    create_post(&mut test_group, token_account).await.unwrap();
+}
+
+#[tokio::test]
+async fn create_post_wrong_mint() {
+    let mut test_group = setup().await;
+    let other_mint = Keypair::new();
+    create_mint(&mut test_group.context, &other_mint).await;
+
+    let owner = test_group.author_authority.pubkey();
+    let token_account = mint_tokens(
+        &mut test_group.context,
+        &other_mint.pubkey(),
+        &owner,
+        MIN_AMOUNT,
+    )
+    .await;
+
+    let result = create_post(&mut test_group, token_account).await;
+    assert_error(result, GenericError::InvalidAccount);
+}
+
+#[tokio::test]
+async fn create_post_token_account_of_another_owner() {
+    let mut test_group = setup().await;
+
+    // the tokens are held by someone other than the author's authority
+    let (mint, owner) = (test_group.mint.pubkey(), Keypair::new().pubkey());
+    let token_account = mint_tokens(&mut test_group.context, &mint, &owner, MIN_AMOUNT).await;
+
+    let result = create_post(&mut test_group, token_account).await;
+    assert_error(result, GenericError::Unauthorized);
+}
+
+#[tokio::test]
+async fn set_post_group_token_gate_zero_min_amount() {
+    let mut test_group = setup().await;
+    let context = &mut test_group.context;
+
+    let instruction = Instruction {
+        program_id: social::ID,
+        accounts: social::accounts::SetPostGroupTokenGate {
+            config: test_group.config,
+            authority: test_group.group_authority.pubkey(),
+            profile: test_group.group_owner,
+            group: test_group.group,
+            parent_group: None,
+            role: None,
+        }
+        .to_account_metas(None),
+        data: social::instruction::SetPostGroupTokenGate {
+            token_gate: Some(TokenGate {
+                mint: test_group.mint.pubkey(),
+                min_amount: 0,
+            }),
+        }
+        .data(),
+    };
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &test_group.group_authority],
+        context.last_blockhash,
+    );
+
+    let result = context.banks_client.process_transaction(transaction).await;
+    assert_error(result, GenericError::InvalidTokenGate);
+}
@@ -0,0 +1,4 @@
+//! Off-chain helpers for clients of the `social` program
+
+pub mod messaging;
+pub mod pda;
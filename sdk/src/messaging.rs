@@ -0,0 +1,266 @@
+//! End-to-end encryption for direct messages between Profiles
+//!
+//! Each Profile publishes an x25519 public key in `Profile.messaging_key`.
+//! The two participants of a Conversation perform a Diffie-Hellman exchange and
+//! derive a symmetric XChaCha20-Poly1305 key that is bound to the Conversation's
+//! address, so the on-chain `Message.ciphertext` is only readable by them.
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rand_core::{OsRng, RngCore};
+use social::state::{Message, Profile};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+/// domain separator used when deriving the symmetric key for a Conversation
+const KEY_DERIVATION_DOMAIN: &[u8] = b"social:direct-message:v1";
+
+/// length of the authentication tag appended to every ciphertext
+pub const TAG_LEN: usize = 16;
+
+/// max length of a plaintext that still fits within `Message::MAX_LEN_CIPHERTEXT`
+pub const MAX_LEN_PLAINTEXT: usize = Message::MAX_LEN_CIPHERTEXT - TAG_LEN;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessagingError {
+    /// the other Profile has not published a `messaging_key`
+    MissingMessagingKey,
+    /// the plaintext will not fit within a Message account
+    MessageTooLong,
+    /// the message could not be encrypted or decrypted (e.g. wrong key or tampered data)
+    Crypto,
+}
+
+impl fmt::Display for MessagingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMessagingKey => write!(f, "the profile has no messaging key"),
+            Self::MessageTooLong => write!(f, "the provided message is too long"),
+            Self::Crypto => write!(f, "unable to encrypt or decrypt the message"),
+        }
+    }
+}
+
+impl std::error::Error for MessagingError {}
+
+/// encrypted message data, ready to be passed to the `send_message` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncryptedMessage {
+    pub nonce: [u8; 24],
+    pub ciphertext: Vec<u8>,
+}
+
+/// x25519 keypair used by a Profile to send and receive direct messages
+pub struct MessagingKeypair {
+    secret: StaticSecret,
+    public: PublicKey,
+}
+
+impl MessagingKeypair {
+    /// generate a new random keypair
+    pub fn generate() -> Self {
+        Self::from_secret(StaticSecret::new(OsRng))
+    }
+
+    /// restore a keypair from its previously stored secret bytes
+    pub fn from_secret_bytes(secret: [u8; 32]) -> Self {
+        Self::from_secret(StaticSecret::from(secret))
+    }
+
+    fn from_secret(secret: StaticSecret) -> Self {
+        let public = PublicKey::from(&secret);
+        Self { secret, public }
+    }
+
+    /// secret bytes to be stored securely by the client
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    /// public key to be published in `Profile.messaging_key`
+    pub fn public_key(&self) -> [u8; 32] {
+        self.public.to_bytes()
+    }
+
+    /// encrypt a message for the `recipient` Profile within the given Conversation
+    pub fn encrypt(
+        &self,
+        recipient: &Profile,
+        conversation: &Pubkey,
+        plaintext: &[u8],
+    ) -> Result<EncryptedMessage, MessagingError> {
+        if plaintext.len() > MAX_LEN_PLAINTEXT {
+            return Err(MessagingError::MessageTooLong);
+        }
+
+        let cipher = self.cipher(recipient, conversation)?;
+
+        let mut nonce = [0u8; 24];
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = cipher
+            .encrypt(XNonce::from_slice(&nonce), plaintext)
+            .map_err(|_| MessagingError::Crypto)?;
+
+        Ok(EncryptedMessage { nonce, ciphertext })
+    }
+
+    /// decrypt a Message sent within a Conversation with the `other` Profile
+    ///
+    /// note: the `other` Profile is the other participant of the Conversation,
+    /// regardless of which of the two profiles actually sent the Message
+    pub fn decrypt(&self, other: &Profile, message: &Message) -> Result<Vec<u8>, MessagingError> {
+        let cipher = self.cipher(other, &message.conversation)?;
+
+        cipher
+            .decrypt(XNonce::from_slice(&message.nonce), message.ciphertext.as_ref())
+            .map_err(|_| MessagingError::Crypto)
+    }
+
+    /// derive the symmetric cipher shared with the `other` Profile for a Conversation
+    fn cipher(
+        &self,
+        other: &Profile,
+        conversation: &Pubkey,
+    ) -> Result<XChaCha20Poly1305, MessagingError> {
+        let their_key = other
+            .messaging_key
+            .ok_or(MessagingError::MissingMessagingKey)?;
+        let shared_secret = self.secret.diffie_hellman(&PublicKey::from(their_key));
+
+        // sort the public keys so both participants derive the exact same key
+        let my_key = self.public_key();
+        let (first, second) = if my_key <= their_key {
+            (my_key, their_key)
+        } else {
+            (their_key, my_key)
+        };
+
+        let key = hashv(&[
+            KEY_DERIVATION_DOMAIN,
+            shared_secret.as_bytes(),
+            &first,
+            &second,
+            conversation.as_ref(),
+        ]);
+
+        Ok(XChaCha20Poly1305::new(Key::from_slice(key.as_ref())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// build a Profile that published the `keypair`'s messaging key
+    fn profile_with_key(keypair: &MessagingKeypair) -> Profile {
+        Profile {
+            messaging_key: Some(keypair.public_key()),
+            ..Profile::default()
+        }
+    }
+
+    /// build the Message account that would store the `encrypted` data
+    fn message(conversation: Pubkey, encrypted: EncryptedMessage) -> Message {
+        Message {
+            conversation,
+            nonce: encrypted.nonce,
+            ciphertext: encrypted.ciphertext,
+            ..Message::default()
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let alice = MessagingKeypair::generate();
+        let bob = MessagingKeypair::generate();
+        let (alice_profile, bob_profile) = (profile_with_key(&alice), profile_with_key(&bob));
+        let conversation = Pubkey::new_unique();
+
+        let encrypted = alice.encrypt(&bob_profile, &conversation, b"gm").unwrap();
+        assert_eq!(encrypted.ciphertext.len(), 2 + TAG_LEN);
+        let message = message(conversation, encrypted);
+
+        // both participants are able to read the message
+        assert_eq!(bob.decrypt(&alice_profile, &message).unwrap(), b"gm");
+        assert_eq!(alice.decrypt(&bob_profile, &message).unwrap(), b"gm");
+    }
+
+    #[test]
+    fn decrypt_with_wrong_key() {
+        let alice = MessagingKeypair::generate();
+        let bob = MessagingKeypair::generate();
+        let eve = MessagingKeypair::generate();
+        let alice_profile = profile_with_key(&alice);
+        let conversation = Pubkey::new_unique();
+
+        let encrypted = alice
+            .encrypt(&profile_with_key(&bob), &conversation, b"gm")
+            .unwrap();
+        let message = message(conversation, encrypted);
+
+        assert_eq!(
+            eve.decrypt(&alice_profile, &message),
+            Err(MessagingError::Crypto)
+        );
+    }
+
+    #[test]
+    fn decrypt_with_wrong_conversation() {
+        let alice = MessagingKeypair::generate();
+        let bob = MessagingKeypair::generate();
+        let alice_profile = profile_with_key(&alice);
+
+        let encrypted = alice
+            .encrypt(&profile_with_key(&bob), &Pubkey::new_unique(), b"gm")
+            .unwrap();
+        // the same ciphertext replayed within another conversation
+        let message = message(Pubkey::new_unique(), encrypted);
+
+        assert_eq!(
+            bob.decrypt(&alice_profile, &message),
+            Err(MessagingError::Crypto)
+        );
+    }
+
+    #[test]
+    fn encrypt_message_too_long() {
+        let alice = MessagingKeypair::generate();
+        let bob_profile = profile_with_key(&MessagingKeypair::generate());
+        let conversation = Pubkey::new_unique();
+
+        let plaintext = vec![0u8; MAX_LEN_PLAINTEXT + 1];
+        assert_eq!(
+            alice.encrypt(&bob_profile, &conversation, &plaintext),
+            Err(MessagingError::MessageTooLong)
+        );
+
+        // the largest allowed plaintext still fits within a Message account
+        let plaintext = vec![0u8; MAX_LEN_PLAINTEXT];
+        let encrypted = alice
+            .encrypt(&bob_profile, &conversation, &plaintext)
+            .unwrap();
+        assert_eq!(encrypted.ciphertext.len(), Message::MAX_LEN_CIPHERTEXT);
+    }
+
+    #[test]
+    fn encrypt_without_messaging_key() {
+        let alice = MessagingKeypair::generate();
+
+        assert_eq!(
+            alice.encrypt(&Profile::default(), &Pubkey::new_unique(), b"gm"),
+            Err(MessagingError::MissingMessagingKey)
+        );
+    }
+
+    #[test]
+    fn restore_from_secret_bytes() {
+        let keypair = MessagingKeypair::generate();
+        let restored = MessagingKeypair::from_secret_bytes(keypair.secret_bytes());
+
+        assert_eq!(restored.public_key(), keypair.public_key());
+    }
+}
@@ -0,0 +1,32 @@
+//! Derive the PDA addresses used by the `social` program
+
+use anchor_lang::prelude::Pubkey;
+use social::state::{Conversation, Message};
+
+/// Derive a Conversation's PDA address from its two participating Profiles
+///
+/// note: the profiles may be provided in any order
+pub fn derive_conversation_address(profile_a: &Pubkey, profile_b: &Pubkey) -> (Pubkey, u8) {
+    let [first, second] = Conversation::sort_profiles(*profile_a, *profile_b);
+
+    Pubkey::find_program_address(
+        &[
+            Conversation::PREFIX_SEED.as_ref(),
+            first.as_ref(),
+            second.as_ref(),
+        ],
+        &social::ID,
+    )
+}
+
+/// Derive a Message's PDA address
+pub fn derive_message_address(conversation: &Pubkey, message_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Message::PREFIX_SEED.as_ref(),
+            conversation.as_ref(),
+            message_id.to_string().as_bytes(),
+        ],
+        &social::ID,
+    )
+}
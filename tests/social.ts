@@ -4,12 +4,38 @@ import type { Social } from "../target/types/social";
 
 import chai, { expect, assert } from "chai";
 import chaiAsPromised from "chai-as-promised";
+import { createAccount, createMint, mintTo } from "@solana/spl-token";
 import {
+  deriveChannelAddress,
+  deriveChannelSeed,
+  deriveBondAddress,
+  deriveConfigAddress,
+  deriveFeeExemptionAddress,
+  deriveConversationAddress,
   deriveLookupAccountAddress,
+  deriveMembershipAddress,
+  deriveMessageAddress,
+  derivePostActivityAddress,
   derivePostAddress,
   derivePostGroupAddress,
+  deriveEndorsementAddress,
+  deriveEngagementAddress,
+  deriveGroupBanAddress,
+  deriveGroupRoleAddress,
+  deriveGroupTreasuryAddress,
   deriveProfileAddress,
+  deriveReactionAddress,
+  deriveReportAddress,
+  deriveTagAddress,
+  deriveTagEntryAddress,
+  deriveTreasuryAddress,
+  deriveUsernameReservationAddress,
+  deriveVouchAddress,
+  GroupPermission,
+  PostLabel,
 } from "../client/accounts";
+import { createNft, verifyCollection } from "../client/nft";
+import { buildVoucherInstruction } from "../client/reservation";
 
 chai.use(chaiAsPromised);
 
@@ -17,7 +43,8 @@ chai.use(chaiAsPromised);
 const provider = anchor.AnchorProvider.env();
 anchor.setProvider(provider);
 const program = anchor.workspace.Social as Program<Social>;
-const payer = provider.wallet;
+// the provider wallet's keypair, which also pays for (and is the mint authority of) test tokens
+const payer = (provider.wallet as anchor.Wallet).payer;
 
 // init the random seed bytes to use
 const random_seed_profile = anchor.web3.Keypair.generate().publicKey.toBytes();
@@ -26,12 +53,145 @@ const random_seed_postGroup = anchor.web3.Keypair.generate().publicKey.toBytes()
 // derive the pda address based on the random
 const [profilePda] = deriveProfileAddress(random_seed_profile);
 const [postGroupPda] = derivePostGroupAddress(random_seed_postGroup);
+const [configPda] = deriveConfigAddress();
+const [treasuryPda] = deriveTreasuryAddress();
 
 // derive the pda address based on the random
 const [postPda] = derivePostAddress(postGroupPda, 0);
 const [replyPda] = derivePostAddress(postPda, 0);
 // todo: get the `post_id` values dynamically from the on-chain accounts
 
+/**
+ * Build the PostGroup input used when creating or updating a post group
+ *
+ * note: the `bump`, `authority`, and any counters are ignored by the program
+ */
+function postGroupInput(
+  randomSeed: Uint8Array,
+  name: string,
+  overrides: Partial<anchor.IdlAccounts<Social>["postGroup"]> = {},
+): anchor.IdlAccounts<Social>["postGroup"] {
+  return {
+    bump: 0,
+    randomSeed: randomSeed as unknown as number[],
+    authority: anchor.web3.PublicKey.default,
+    pendingAuthority: null,
+    parentGroup: null,
+    channelCount: 0,
+    inheritMembership: false,
+    inheritModeration: false,
+    postCount: 0,
+    archived: false,
+    minReputation: new anchor.BN(0),
+    membershipMode: { allowlist: {} },
+    rateLimit: null,
+    postingBond: null,
+    tokenGate: null,
+    collectionGate: null,
+    category: 0,
+    contentHash: new Array(32).fill(0),
+    name,
+    description: "",
+    imageUri: "",
+    metadataUri: "",
+    ...overrides,
+  };
+}
+
+/**
+ * Create a new profile (with its own authority) for tests that need more than one profile
+ */
+async function createTestProfile(
+  username: string,
+  feeExemption: anchor.web3.PublicKey | null = null,
+  treasury: anchor.web3.PublicKey | null = null,
+) {
+  const authority = anchor.web3.Keypair.generate();
+  const randomSeed = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [profile] = deriveProfileAddress(randomSeed);
+  const [lookupAccount] = deriveLookupAccountAddress("profile", username);
+
+  await program.methods
+    .createProfile({
+      bump: 0, // this is ignored
+      randomSeed: randomSeed as unknown as number[],
+      authority: authority.publicKey,
+      name: username,
+      username,
+      imageUri: "",
+      metadataUri: "",
+      messagingKey: null,
+      endorsementCount: 0,
+      reputation: new anchor.BN(0),
+    })
+    .accounts({
+      config: configPda,
+      treasury,
+      feeExemption,
+      reservation: deriveUsernameReservationAddress(username)[0],
+      instructionsSysvar: null,
+      authority: authority.publicKey,
+      profile,
+      lookupAccount,
+    })
+    .signers([authority])
+    .rpc();
+
+  return { authority, profile, randomSeed };
+}
+
+// protocol fees are disabled by default, so most tests do not need to account for them
+const noFees = {
+  createProfile: new anchor.BN(0),
+  changeUsername: new anchor.BN(0),
+  createPostGroup: new anchor.BN(0),
+  createPost: new anchor.BN(0),
+  vouch: new anchor.BN(0),
+  usernamePrices: [0, 0, 0, 0, 0].map(price => new anchor.BN(price)),
+};
+
+/**
+ * Build the Config input used when initializing or updating the global config
+ *
+ * note: the `bump`, `admin`, and `paused` flag are ignored when updating the config
+ */
+function configInput(
+  overrides: Partial<anchor.IdlAccounts<Social>["config"]> = {},
+): anchor.IdlAccounts<Social>["config"] {
+  return {
+    bump: 0,
+    admin: payer.publicKey,
+    paused: false,
+    maxTagsPerPost: 5,
+    maxCoAuthors: 4,
+    fees: noFees,
+    // usernames never expire by default
+    usernameLeaseDuration: new anchor.BN(0),
+    usernameGracePeriod: new anchor.BN(0),
+    reservationKey: null,
+    ...overrides,
+  };
+}
+
+// the global config must exist before any other instruction can be used
+before(async () => {
+  // only the program's upgrade authority may create the config
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBytes()],
+    new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+  );
+
+  await program.methods
+    .initializeConfig(configInput())
+    .accounts({
+      program: program.programId,
+      programData,
+      config: configPda,
+      treasury: treasuryPda,
+    })
+    .rpc();
+});
+
 describe("profile", () => {
   //
   const profileData: anchor.IdlAccounts<Social>["profile"] = {
@@ -42,6 +202,9 @@ describe("profile", () => {
     username: "username_default",
     imageUri: "imageUri_default",
     metadataUri: "metadataUri_default",
+    messagingKey: null,
+    endorsementCount: 0,
+    reputation: new anchor.BN(0),
   };
 
   //
@@ -53,6 +216,11 @@ describe("profile", () => {
     await program.methods
       .createProfile(profileData)
       .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: deriveUsernameReservationAddress(profileData.username)[0],
+        instructionsSysvar: null,
         // payer: payer.publicKey,
         authority: payer.publicKey,
         profile: profilePda,
@@ -119,6 +287,9 @@ describe("profile", () => {
       name: "new name",
       imageUri: "new imageUri",
       metadataUri: "new metadataUri",
+      messagingKey: null,
+      endorsementCount: 0,
+      reputation: new anchor.BN(0),
     };
 
     const wrongAuthority = anchor.web3.Keypair.generate();
@@ -127,6 +298,7 @@ describe("profile", () => {
       program.methods
         .updateProfile(newProfileData)
         .accounts({
+          config: configPda,
           authority: wrongAuthority.publicKey,
           profile: profilePda,
         })
@@ -148,11 +320,15 @@ describe("profile", () => {
       name: "new name",
       imageUri: "new imageUri",
       metadataUri: "new metadataUri",
+      messagingKey: null,
+      endorsementCount: 0,
+      reputation: new anchor.BN(0),
     };
 
     await program.methods
       .updateProfile(newProfileData)
       .accounts({
+        config: configPda,
         // payer: payer.publicKey,
         profile: profilePda,
       })
@@ -184,6 +360,11 @@ describe("profile", () => {
       program.methods
         .changeUsername(random_seed_profile as unknown as number[], new_username)
         .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          reservation: deriveUsernameReservationAddress(new_username)[0],
+          instructionsSysvar: null,
           // note: when not provided, Anchor should auto-magically set this to the fee payer
           authority: wrongAuthority.publicKey,
           profile: profilePda,
@@ -212,6 +393,11 @@ describe("profile", () => {
     await program.methods
       .changeUsername(random_seed_profile as unknown as number[], new_username)
       .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: deriveUsernameReservationAddress(new_username)[0],
+        instructionsSysvar: null,
         // note: when not provided, Anchor should auto-magically set this to the fee payer
         // authority: payer.publicKey,
         profile: profilePda,
@@ -256,8 +442,11 @@ describe("post_group", () => {
     console.log("\t", "post group address:", postGroupPda.toBase58());
 
     await program.methods
-      .createPostGroup(random_seed_postGroup as unknown as number[], postGroupName)
+      .createPostGroup(postGroupInput(random_seed_postGroup, postGroupName))
       .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
         author: profilePda,
         group: postGroupPda,
         lookupAccount: lookupAccountPda,
@@ -304,6 +493,125 @@ describe("post_group", () => {
       "Expected 'authority' to be the 'profilePda'",
     );
   });
+  //
+  it("update post group", async () => {
+    await program.methods
+      .updatePostGroup(
+        postGroupInput(random_seed_postGroup, "this is ignored", {
+          description: "a group for testing",
+          imageUri: "https://example.com/group.png",
+          metadataUri: "https://example.com/group.json",
+          contentHash: new Array(32).fill(1),
+          category: 7,
+        }),
+      )
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(postGroupPda);
+
+    assert(group.name === postGroupName, "Expected 'name' to NOT change");
+    assert(group.minReputation.eqn(0), "Expected 'minReputation' to match");
+    assert(group.description === "a group for testing", "Expected 'description' to update");
+    assert(group.imageUri === "https://example.com/group.png", "Expected 'imageUri' to update");
+    assert(
+      group.metadataUri === "https://example.com/group.json",
+      "Expected 'metadataUri' to update",
+    );
+    assert(group.contentHash.every(byte => byte === 1), "Expected 'contentHash' to update");
+    assert(group.category === 7, "Expected 'category' to update");
+  });
+
+  //
+  it("update post group: description too long", async () => {
+    await expect(
+      program.methods
+        .updatePostGroup(
+          postGroupInput(random_seed_postGroup, "this is ignored", {
+            description: "a".repeat(257),
+          }),
+        )
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: postGroupPda,
+          parentGroup: null,
+          role: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: DescriptionTooLong");
+  });
+
+  //
+  it("rename post group", async () => {
+    const newName = "renamed";
+    const [newLookupAccountPda] = deriveLookupAccountAddress("post_group", newName);
+
+    await program.methods
+      .renamePostGroup(newName)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        oldLookupAccount: lookupAccountPda,
+        newLookupAccount: newLookupAccountPda,
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(postGroupPda);
+    const newLookupAccount = await program.account.lookupAccount.fetch(newLookupAccountPda);
+
+    assert(group.name === newName, "Expected 'name' to update");
+    assert(
+      newLookupAccount.address.toBase58() === postGroupPda.toBase58(),
+      "Expected 'address' to be the 'postGroupPda'",
+    );
+
+    // ensure the old lookup account was closed
+    await expect(
+      program.account.lookupAccount.fetch(lookupAccountPda),
+    ).to.eventually.be.rejectedWith("Account does not exist or has no data");
+  });
+
+  //
+  it("migrate post group: only legacy post groups", async () => {
+    // the group was created with its current size, so there is nothing to migrate
+    await expect(
+      program.methods
+        .migratePostGroup()
+        .accounts({
+          config: configPda,
+          group: postGroupPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidAccount");
+  });
+
+  it("migrate post group: keeps legacy post groups owner-only", async () => {
+    // loaded at genesis (see `Anchor.toml`) with the layout of the initial release
+    const legacyGroup = new anchor.web3.PublicKey("4zaC3zTokY79SSEUf11x6Shdfz8n9tqqXhuFQtgqjV4t");
+
+    await program.methods
+      .migratePostGroup()
+      .accounts({
+        config: configPda,
+        group: legacyGroup,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(legacyGroup);
+
+    assert(group.name === "legacy", "Expected 'name' to be kept");
+    assert(group.postCount === 3, "Expected 'postCount' to be kept");
+    assert("allowlist" in group.membershipMode, "Expected an 'allowlist' membership mode");
+  });
 });
 
 describe("post", () => {
@@ -314,11 +622,24 @@ describe("post", () => {
     const metadataUri = "metadataUri_default";
 
     await program.methods
-      .createPost(metadataUri)
+      .createPost(metadataUri, [], { primaryAuthor: {} }, 0)
       .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
         author: profilePda,
         post: postPda,
         group: postGroupPda,
+        parentGroup: null,
+        // the group's authority does not need a membership to post
+        membership: null,
+        ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
       })
       .rpc();
 
@@ -337,6 +658,150 @@ describe("post", () => {
     );
   });
 
+  //
+  it("create post with tags", async () => {
+    const group = await program.account.postGroup.fetch(postGroupPda);
+    const [taggedPostPda] = derivePostAddress(postGroupPda, group.postCount);
+
+    // tags are normalized on-chain, so `#Solana` is indexed under `solana`
+    const [tagPda] = deriveTagAddress("solana");
+    const [tagEntryPda] = deriveTagEntryAddress(tagPda, 0);
+
+    await program.methods
+      .createPost("tagged metadataUri", ["#Solana"], { primaryAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        post: taggedPostPda,
+        group: postGroupPda,
+        parentGroup: null,
+        membership: null,
+        ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .remainingAccounts([
+        { pubkey: tagPda, isSigner: false, isWritable: true },
+        { pubkey: tagEntryPda, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    // get the tag and its first entry from the chain
+    const tag = await program.account.tag.fetch(tagPda);
+    const tagEntry = await program.account.tagEntry.fetch(tagEntryPda);
+
+    // perform the assertions
+    assert(tag.name === "solana", "Expected 'name' to be normalized");
+    assert(tag.postCount === 1, "Expected 'postCount' to increment");
+    assert(
+      tagEntry.post.toBase58() === taggedPostPda.toBase58(),
+      "Expected the tag entry to point to the tagged post",
+    );
+  });
+
+  //
+  it("create post with tags: invalid tag", async () => {
+    const group = await program.account.postGroup.fetch(postGroupPda);
+    const [taggedPostPda] = derivePostAddress(postGroupPda, group.postCount);
+
+    await expect(
+      program.methods
+        .createPost("tagged metadataUri", ["not a tag!"], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          post: taggedPostPda,
+          group: postGroupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .remainingAccounts([
+          { pubkey: anchor.web3.Keypair.generate().publicKey, isSigner: false, isWritable: true },
+          { pubkey: anchor.web3.Keypair.generate().publicKey, isSigner: false, isWritable: true },
+        ])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidTag");
+  });
+
+  //
+  it("create co-authored post", async () => {
+    // create the co-author's profile, with its own authority
+    const { authority: coAuthorAuthority, profile: coAuthorPda } = await createTestProfile(
+      "co_author",
+    );
+
+    const group = await program.account.postGroup.fetch(postGroupPda);
+    const [coAuthoredPostPda] = derivePostAddress(postGroupPda, group.postCount);
+
+    await program.methods
+      .createPost("co-authored metadataUri", [], { anyAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        post: coAuthoredPostPda,
+        group: postGroupPda,
+        parentGroup: null,
+        membership: null,
+        ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .remainingAccounts([
+        { pubkey: coAuthorPda, isSigner: false, isWritable: false },
+        { pubkey: coAuthorAuthority.publicKey, isSigner: true, isWritable: false },
+      ])
+      .signers([coAuthorAuthority])
+      .rpc();
+
+    let post = await program.account.post.fetch(coAuthoredPostPda);
+
+    assert(post.coAuthors.length === 1, "Expected a single co-author");
+    assert(
+      post.coAuthors[0].toBase58() === coAuthorPda.toBase58(),
+      "Expected the co-author to be 'coAuthorPda'",
+    );
+
+    // any author is allowed to edit the post
+    const metadataUri = "edited by co-author";
+
+    await program.methods
+      .updatePost(metadataUri, 0)
+      .accounts({
+        config: configPda,
+        authority: coAuthorAuthority.publicKey,
+        author: coAuthorPda,
+        post: coAuthoredPostPda,
+        group: postGroupPda,
+      })
+      .signers([coAuthorAuthority])
+      .rpc();
+
+    post = await program.account.post.fetch(coAuthoredPostPda);
+
+    assert(post.metadataUri === metadataUri, "Expected 'metadataUri' to update");
+  });
+
   //
   it("update post: incorrect authority", async () => {
     //
@@ -346,8 +811,9 @@ describe("post", () => {
 
     try {
       await program.methods
-        .updatePost(metadataUri)
+        .updatePost(metadataUri, 0)
         .accounts({
+          config: configPda,
           // note: when not provided, Anchor should auto-magically set this to the fee payer
           authority: wrongAuthority.publicKey,
           author: profilePda,
@@ -378,8 +844,9 @@ describe("post", () => {
     const metadataUri = "winning metadataUri";
 
     await program.methods
-      .updatePost(metadataUri)
+      .updatePost(metadataUri, 0)
       .accounts({
+        config: configPda,
         // note: when not provided, Anchor should auto-magically set this to the fee payer
         // authority: payer.publicKey,
         author: profilePda,
@@ -405,11 +872,26 @@ describe("post", () => {
     const metadataUri = "reply_metadataUri";
 
     await program.methods
-      .createReply(metadataUri)
+      .createReply(metadataUri, [], 0)
       .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
         author: profilePda,
         parentPost: postPda,
         reply: replyPda,
+        group: postGroupPda,
+        parentGroup: null,
+        parentAuthor: profilePda,
+        // replying to your own post does not affect reputation
+        engagement: null,
+        ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
       })
       .rpc();
 
@@ -447,8 +929,9 @@ describe("post", () => {
     const metadataUri = "new reply metadataUri";
 
     await program.methods
-      .updatePost(metadataUri)
+      .updatePost(metadataUri, 0)
       .accounts({
+        config: configPda,
         // note: when not provided, Anchor should auto-magically set this to the fee payer
         // authority: payer.publicKey,
         author: profilePda,
@@ -466,4 +949,3026 @@ describe("post", () => {
 
     // todo
   });
+
+  //
+  it("migrate post: only legacy posts", async () => {
+    // the post was created with its current size, so there is nothing to migrate
+    await expect(
+      program.methods
+        .migratePost()
+        .accounts({
+          config: configPda,
+          post: postPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidAccount");
+
+    // other accounts can not be migrated as a post
+    await expect(
+      program.methods
+        .migratePost()
+        .accounts({
+          config: configPda,
+          post: profilePda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidAccount");
+  });
+});
+
+describe("direct_message", () => {
+  // the recipient profile uses its own authority
+  const recipientAuthority = anchor.web3.Keypair.generate();
+  const random_seed_recipient = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [recipientPda] = deriveProfileAddress(random_seed_recipient);
+
+  // note: the actual x25519 keys and encryption are handled by the `social-sdk` crate
+  const messagingKey = Array.from(anchor.web3.Keypair.generate().publicKey.toBytes());
+
+  const [conversationPda] = deriveConversationAddress(profilePda, recipientPda);
+  const [messagePda] = deriveMessageAddress(conversationPda, 0);
+
+  //
+  it("create profile with messaging key", async () => {
+    const recipientData: anchor.IdlAccounts<Social>["profile"] = {
+      bump: 0, // this is ignored
+      randomSeed: random_seed_recipient as unknown as number[],
+      authority: recipientAuthority.publicKey,
+      name: "recipient",
+      username: "dm_recipient",
+      imageUri: "",
+      metadataUri: "",
+      messagingKey,
+      endorsementCount: 0,
+      reputation: new anchor.BN(0),
+    };
+
+    const [lookupAccountPda] = deriveLookupAccountAddress("profile", recipientData.username);
+
+    await program.methods
+      .createProfile(recipientData)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: deriveUsernameReservationAddress(recipientData.username)[0],
+        instructionsSysvar: null,
+        authority: recipientAuthority.publicKey,
+        profile: recipientPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .signers([recipientAuthority])
+      .rpc();
+
+    const recipient = await program.account.profile.fetch(recipientPda);
+
+    assert(
+      JSON.stringify(recipient.messagingKey) === JSON.stringify(messagingKey),
+      "Expected 'messagingKey' to match",
+    );
+  });
+
+  //
+  it("create conversation", async () => {
+    console.log("\t", "conversation address:", conversationPda.toBase58());
+
+    await program.methods
+      .createConversation()
+      .accounts({
+        config: configPda,
+        sender: profilePda,
+        recipient: recipientPda,
+        conversation: conversationPda,
+      })
+      .rpc();
+
+    const conversation = await program.account.conversation.fetch(conversationPda);
+
+    assert(
+      conversation.profiles.some(key => key.toBase58() === profilePda.toBase58()) &&
+        conversation.profiles.some(key => key.toBase58() === recipientPda.toBase58()),
+      "Expected both profiles to be participants",
+    );
+    assert(conversation.messageCount === 0, "Expected 'messageCount' to be 0");
+  });
+
+  //
+  it("send message: incorrect authority", async () => {
+    const wrongAuthority = anchor.web3.Keypair.generate();
+
+    await expect(
+      program.methods
+        .sendMessage(new Array(24).fill(0), Buffer.from("ciphertext"))
+        .accounts({
+          config: configPda,
+          authority: wrongAuthority.publicKey,
+          sender: recipientPda,
+          conversation: conversationPda,
+          message: messagePda,
+        })
+        .signers([wrongAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+
+  //
+  it("send message", async () => {
+    console.log("\t", "message address:", messagePda.toBase58());
+
+    const nonce = new Array(24).fill(7);
+    const ciphertext = Buffer.from("not actually encrypted in this test");
+
+    await program.methods
+      .sendMessage(nonce, ciphertext)
+      .accounts({
+        config: configPda,
+        authority: recipientAuthority.publicKey,
+        sender: recipientPda,
+        conversation: conversationPda,
+        message: messagePda,
+      })
+      .signers([recipientAuthority])
+      .rpc();
+
+    const conversation = await program.account.conversation.fetch(conversationPda);
+    const message = await program.account.message.fetch(messagePda);
+
+    assert(conversation.messageCount === 1, "Expected 'messageCount' to increment");
+    assert(message.messageId === 0, "Expected 'messageId' to be 0");
+    assert(
+      message.sender.toBase58() === recipientPda.toBase58(),
+      "Expected 'sender' to be the 'recipientPda'",
+    );
+    assert(Buffer.from(message.ciphertext).equals(ciphertext), "Expected 'ciphertext' to match");
+  });
+});
+
+describe("vouch", () => {
+  const skill = 7;
+
+  //
+  it("vouch for profile", async () => {
+    const { profile: endorseePda } = await createTestProfile("endorsee");
+    const [vouchPda] = deriveVouchAddress(profilePda, endorseePda, skill);
+
+    await program.methods
+      .vouch(skill)
+      .accounts({
+        config: configPda,
+        endorser: profilePda,
+        endorsee: endorseePda,
+        vouch: vouchPda,
+        endorsement: deriveEndorsementAddress(profilePda, endorseePda)[0],
+        treasury: null,
+        feeExemption: null,
+      })
+      .rpc();
+
+    let endorsee = await program.account.profile.fetch(endorseePda);
+    const vouch = await program.account.vouch.fetch(vouchPda);
+
+    assert(endorsee.endorsementCount === 1, "Expected 'endorsementCount' to increment");
+    assert(vouch.skill === skill, "Expected 'skill' to match");
+    assert(
+      vouch.endorser.toBase58() === profilePda.toBase58(),
+      "Expected 'endorser' to be the 'profilePda'",
+    );
+
+    // revoking the vouch closes the account
+    await program.methods
+      .revokeVouch()
+      .accounts({
+        config: configPda,
+        endorser: profilePda,
+        endorsee: endorseePda,
+        vouch: vouchPda,
+        endorsement: deriveEndorsementAddress(profilePda, endorseePda)[0],
+      })
+      .rpc();
+
+    endorsee = await program.account.profile.fetch(endorseePda);
+
+    assert(endorsee.endorsementCount === 0, "Expected 'endorsementCount' to decrement");
+    await expect(program.account.vouch.fetch(vouchPda)).to.eventually.be.rejectedWith(
+      "Account does not exist or has no data",
+    );
+  });
+
+  //
+  it("vouch for profile: each endorser is only counted once", async () => {
+    const { profile: endorseePda } = await createTestProfile("multi_skill_endorsee");
+    const [endorsementPda] = deriveEndorsementAddress(profilePda, endorseePda);
+
+    const vouchFor = (vouchSkill: number) =>
+      program.methods
+        .vouch(vouchSkill)
+        .accounts({
+          config: configPda,
+          endorser: profilePda,
+          endorsee: endorseePda,
+          vouch: deriveVouchAddress(profilePda, endorseePda, vouchSkill)[0],
+          endorsement: endorsementPda,
+          treasury: null,
+          feeExemption: null,
+        })
+        .rpc();
+
+    // vouching for several skills does not make the endorser count several times
+    for (const vouchSkill of [0, 1, 2]) {
+      await vouchFor(vouchSkill);
+    }
+
+    const endorsee = await program.account.profile.fetch(endorseePda);
+    const endorsement = await program.account.endorsement.fetch(endorsementPda);
+
+    assert(endorsee.endorsementCount === 1, "Expected the endorser to only be counted once");
+    assert(endorsement.vouchCount === 3, "Expected every vouch to be tracked");
+  });
+
+  //
+  it("vouch for profile: self endorsement", async () => {
+    const [vouchPda] = deriveVouchAddress(profilePda, profilePda, skill);
+
+    await expect(
+      program.methods
+        .vouch(skill)
+        .accounts({
+          config: configPda,
+          endorser: profilePda,
+          endorsee: profilePda,
+          vouch: vouchPda,
+          endorsement: deriveEndorsementAddress(profilePda, profilePda)[0],
+          treasury: null,
+          feeExemption: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidVouch");
+  });
+
+  //
+  it("vouch for profile: profiles of the same authority", async () => {
+    const username = "sibling_profile";
+    const randomSeed = anchor.web3.Keypair.generate().publicKey.toBytes();
+    const [siblingPda] = deriveProfileAddress(randomSeed);
+
+    // another profile owned by the same authority as the `profilePda`
+    await program.methods
+      .createProfile({
+        bump: 0, // this is ignored
+        randomSeed: randomSeed as unknown as number[],
+        authority: payer.publicKey,
+        name: username,
+        username,
+        imageUri: "",
+        metadataUri: "",
+        messagingKey: null,
+        endorsementCount: 0,
+        reputation: new anchor.BN(0),
+      })
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: deriveUsernameReservationAddress(username)[0],
+        instructionsSysvar: null,
+        authority: payer.publicKey,
+        profile: siblingPda,
+        lookupAccount: deriveLookupAccountAddress("profile", username)[0],
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .vouch(skill)
+        .accounts({
+          config: configPda,
+          endorser: profilePda,
+          endorsee: siblingPda,
+          vouch: deriveVouchAddress(profilePda, siblingPda, skill)[0],
+          endorsement: deriveEndorsementAddress(profilePda, siblingPda)[0],
+          treasury: null,
+          feeExemption: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidVouch");
+  });
+});
+
+describe("reputation", () => {
+  /**
+   * have several other profiles vouch for the `endorseePda`, establishing it as an engager
+   */
+  async function establishEngager(endorseePda: anchor.web3.PublicKey, prefix: string) {
+    for (let i = 0; i < 3; i++) {
+      const { authority, profile: endorserPda } = await createTestProfile(`${prefix}_${i}`);
+
+      await program.methods
+        .vouch(0)
+        .accounts({
+          config: configPda,
+          authority: authority.publicKey,
+          endorser: endorserPda,
+          endorsee: endorseePda,
+          vouch: deriveVouchAddress(endorserPda, endorseePda, 0)[0],
+          endorsement: deriveEndorsementAddress(endorserPda, endorseePda)[0],
+          treasury: null,
+          feeExemption: null,
+        })
+        .signers([authority])
+        .rpc();
+    }
+  }
+
+  //
+  it("react to post", async () => {
+    const { authority: reactorAuthority, profile: reactorPda } = await createTestProfile(
+      "reactor",
+    );
+    await establishEngager(reactorPda, "reactor_endorser");
+    const [reactionPda] = deriveReactionAddress(postPda, reactorPda);
+    const [engagementPda] = deriveEngagementAddress(reactorPda, profilePda);
+
+    const before = await program.account.profile.fetch(profilePda);
+
+    await program.methods
+      .reactToPost(1)
+      .accounts({
+        config: configPda,
+        authority: reactorAuthority.publicKey,
+        profile: reactorPda,
+        post: postPda,
+        postAuthor: profilePda,
+        reaction: reactionPda,
+        engagement: engagementPda,
+      })
+      .signers([reactorAuthority])
+      .rpc();
+
+    const after = await program.account.profile.fetch(profilePda);
+    const engagement = await program.account.engagement.fetch(engagementPda);
+
+    assert(
+      after.reputation.eq(before.reputation.addn(1)),
+      "Expected the post author's 'reputation' to increment",
+    );
+    assert(engagement.count === 1, "Expected the engagement 'count' to increment");
+  });
+
+  //
+  it("react to post: self engagement", async () => {
+    const [reactionPda] = deriveReactionAddress(postPda, profilePda);
+
+    const before = await program.account.profile.fetch(profilePda);
+
+    await program.methods
+      .reactToPost(1)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        post: postPda,
+        postAuthor: profilePda,
+        reaction: reactionPda,
+        engagement: null,
+      })
+      .rpc();
+
+    const after = await program.account.profile.fetch(profilePda);
+
+    assert(after.reputation.eq(before.reputation), "Expected 'reputation' to NOT change");
+  });
+
+  //
+  it("react to post: reputation farming", async () => {
+    const before = await program.account.profile.fetch(profilePda);
+
+    // fresh profiles are cheap to create, so their engagement never awards reputation
+    for (let i = 0; i < 3; i++) {
+      const { authority: farmerAuthority, profile: farmerPda } = await createTestProfile(
+        `farmer_${i}`,
+      );
+
+      await program.methods
+        .reactToPost(1)
+        .accounts({
+          config: configPda,
+          authority: farmerAuthority.publicKey,
+          profile: farmerPda,
+          post: postPda,
+          postAuthor: profilePda,
+          reaction: deriveReactionAddress(postPda, farmerPda)[0],
+          engagement: deriveEngagementAddress(farmerPda, profilePda)[0],
+        })
+        .signers([farmerAuthority])
+        .rpc();
+    }
+
+    const after = await program.account.profile.fetch(profilePda);
+
+    assert(after.reputation.eq(before.reputation), "Expected 'reputation' to NOT change");
+  });
+});
+
+describe("post_group transfer", () => {
+  const random_seed_transferGroup = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [transferGroupPda] = derivePostGroupAddress(random_seed_transferGroup);
+  const transferGroupName = "transfer_me";
+  const [lookupAccountPda] = deriveLookupAccountAddress("post_group", transferGroupName);
+
+  //
+  it("transfer post group to another profile", async () => {
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_transferGroup, transferGroupName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: transferGroupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const { authority: successorAuthority, profile: successorPda } = await createTestProfile(
+      "successor",
+    );
+
+    // step 1: the current owner starts the transfer
+    await program.methods
+      .transferPostGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: transferGroupPda,
+        newAuthority: successorPda,
+      })
+      .rpc();
+
+    let group = await program.account.postGroup.fetch(transferGroupPda);
+    assert(
+      group.pendingAuthority.toBase58() === successorPda.toBase58(),
+      "Expected 'pendingAuthority' to be the 'successorPda'",
+    );
+    assert(
+      group.authority.toBase58() === profilePda.toBase58(),
+      "Expected 'authority' to NOT change until accepted",
+    );
+
+    // step 2: the successor accepts the transfer
+    await program.methods
+      .acceptPostGroupTransfer()
+      .accounts({
+        config: configPda,
+        authority: successorAuthority.publicKey,
+        profile: successorPda,
+        group: transferGroupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .signers([successorAuthority])
+      .rpc();
+
+    group = await program.account.postGroup.fetch(transferGroupPda);
+    const lookupAccount = await program.account.lookupAccount.fetch(lookupAccountPda);
+
+    assert(
+      group.authority.toBase58() === successorPda.toBase58(),
+      "Expected 'authority' to be the 'successorPda'",
+    );
+    assert(group.pendingAuthority === null, "Expected 'pendingAuthority' to be cleared");
+    assert(
+      lookupAccount.authority.toBase58() === successorPda.toBase58(),
+      "Expected the lookup account 'authority' to be the 'successorPda'",
+    );
+  });
+
+  //
+  it("accept post group transfer: not the pending authority", async () => {
+    await expect(
+      program.methods
+        .acceptPostGroupTransfer()
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: transferGroupPda,
+          lookupAccount: lookupAccountPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+});
+
+describe("membership", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "members_only";
+
+  //
+  it("request and approve membership", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(
+        postGroupInput(random_seed_group, groupName, { membershipMode: { approval: {} } }),
+      )
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const { authority: memberAuthority, profile: memberPda } = await createTestProfile("member");
+    const [membershipPda] = deriveMembershipAddress(groupPda, memberPda);
+    const [memberPostPda] = derivePostAddress(groupPda, 0);
+
+    await program.methods
+      .requestMembership()
+      .accounts({
+        config: configPda,
+        authority: memberAuthority.publicKey,
+        profile: memberPda,
+        group: groupPda,
+        membership: membershipPda,
+      })
+      .signers([memberAuthority])
+      .rpc();
+
+    // pending members are not allowed to post yet
+    await expect(
+      program.methods
+        .createPost("member post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: memberAuthority.publicKey,
+          author: memberPda,
+          post: memberPostPda,
+          group: groupPda,
+          parentGroup: null,
+          membership: membershipPda,
+          ban: deriveGroupBanAddress(groupPda, memberPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .signers([memberAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: NotAMember");
+
+    await program.methods
+      .approveMembership()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        membership: membershipPda,
+        role: null,
+      })
+      .rpc();
+
+    const membership = await program.account.membership.fetch(membershipPda);
+    assert("active" in membership.status, "Expected the membership to be active");
+
+    await program.methods
+      .createPost("member post", [], { primaryAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        authority: memberAuthority.publicKey,
+        author: memberPda,
+        post: memberPostPda,
+        group: groupPda,
+        parentGroup: null,
+        membership: membershipPda,
+        ban: deriveGroupBanAddress(groupPda, memberPda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .signers([memberAuthority])
+      .rpc();
+
+    const post = await program.account.post.fetch(memberPostPda);
+    assert(post.author.toBase58() === memberPda.toBase58(), "Expected 'author' to be the member");
+  });
+
+  //
+  it("create post: not a member", async () => {
+    const { authority: outsiderAuthority, profile: outsiderPda } = await createTestProfile(
+      "outsider",
+    );
+    const group = await program.account.postGroup.fetch(groupPda);
+    const [postPda] = derivePostAddress(groupPda, group.postCount);
+
+    await expect(
+      program.methods
+        .createPost("outsider post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: outsiderAuthority.publicKey,
+          author: outsiderPda,
+          post: postPda,
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, outsiderPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .signers([outsiderAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: NotAMember");
+  });
+});
+
+describe("group roles", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "moderated";
+
+  //
+  it("moderator with MANAGE_MEMBERS can add members", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_group, groupName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const { authority: moderatorAuthority, profile: moderatorPda } = await createTestProfile(
+      "moderator",
+    );
+    const [moderatorRolePda] = deriveGroupRoleAddress(groupPda, moderatorPda);
+
+    // unknown permissions can not be granted
+    await expect(
+      program.methods
+        .assignRole(1 << 15)
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: groupPda,
+          parentGroup: null,
+          role: null,
+          assignee: moderatorPda,
+          assigneeRole: moderatorRolePda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidPermissions");
+
+    // the group authority assigns the moderator role
+    await program.methods
+      .assignRole(GroupPermission.MANAGE_MEMBERS)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+        assignee: moderatorPda,
+        assigneeRole: moderatorRolePda,
+      })
+      .rpc();
+
+    const role = await program.account.groupRole.fetch(moderatorRolePda);
+    assert(role.permissions === GroupPermission.MANAGE_MEMBERS, "Expected 'permissions' to match");
+
+    // the moderator can now manage the group's members
+    const { profile: memberPda } = await createTestProfile("moderated_member");
+    const [membershipPda] = deriveMembershipAddress(groupPda, memberPda);
+
+    await program.methods
+      .addMember()
+      .accounts({
+        config: configPda,
+        authority: moderatorAuthority.publicKey,
+        profile: moderatorPda,
+        group: groupPda,
+        parentGroup: null,
+        member: memberPda,
+        membership: membershipPda,
+        role: moderatorRolePda,
+      })
+      .signers([moderatorAuthority])
+      .rpc();
+
+    const membership = await program.account.membership.fetch(membershipPda);
+    assert("active" in membership.status, "Expected the membership to be active");
+
+    // and change who is allowed to post
+    await program.methods
+      .setPostGroupTokenGate(null)
+      .accounts({
+        config: configPda,
+        authority: moderatorAuthority.publicKey,
+        profile: moderatorPda,
+        group: groupPda,
+        parentGroup: null,
+        role: moderatorRolePda,
+      })
+      .signers([moderatorAuthority])
+      .rpc();
+
+    // but is not allowed to edit the group's metadata
+    await expect(
+      program.methods
+        .updatePostGroup(postGroupInput(random_seed_group, groupName))
+        .accounts({
+          config: configPda,
+          authority: moderatorAuthority.publicKey,
+          profile: moderatorPda,
+          group: groupPda,
+          parentGroup: null,
+          role: moderatorRolePda,
+        })
+        .signers([moderatorAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+
+    // nor grant permissions it does not have itself
+    const { profile: otherPda } = await createTestProfile("other_moderator");
+    const [otherRolePda] = deriveGroupRoleAddress(groupPda, otherPda);
+
+    await expect(
+      program.methods
+        .assignRole(GroupPermission.EDIT_METADATA)
+        .accounts({
+          config: configPda,
+          authority: moderatorAuthority.publicKey,
+          profile: moderatorPda,
+          group: groupPda,
+          parentGroup: null,
+          role: moderatorRolePda,
+          assignee: otherPda,
+          assigneeRole: otherRolePda,
+        })
+        .signers([moderatorAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+
+  //
+  it("moderator with EDIT_METADATA can not change the posting rules", async () => {
+    const { authority: editorAuthority, profile: editorPda } = await createTestProfile(
+      "metadata_editor",
+    );
+    const [editorRolePda] = deriveGroupRoleAddress(groupPda, editorPda);
+
+    await program.methods
+      .assignRole(GroupPermission.EDIT_METADATA)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+        assignee: editorPda,
+        assigneeRole: editorRolePda,
+      })
+      .rpc();
+
+    const updatePostGroup = (overrides: Parameters<typeof postGroupInput>[2]) =>
+      program.methods
+        .updatePostGroup(postGroupInput(random_seed_group, groupName, overrides))
+        .accounts({
+          config: configPda,
+          authority: editorAuthority.publicKey,
+          profile: editorPda,
+          group: groupPda,
+          parentGroup: null,
+          role: editorRolePda,
+        })
+        .signers([editorAuthority])
+        .rpc();
+
+    await updatePostGroup({ description: "a moderated community" });
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(group.description === "a moderated community", "Expected 'description' to match");
+
+    await expect(
+      updatePostGroup({ minReputation: new anchor.BN(100) }),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+    await expect(
+      updatePostGroup({ membershipMode: { open: {} } }),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+    await expect(
+      program.methods
+        .setPostGroupBond({
+          amount: new anchor.BN(anchor.web3.LAMPORTS_PER_SOL),
+          cooldownSlots: new anchor.BN(0),
+        })
+        .accounts({
+          config: configPda,
+          authority: editorAuthority.publicKey,
+          profile: editorPda,
+          group: groupPda,
+          parentGroup: null,
+          role: editorRolePda,
+        })
+        .signers([editorAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+
+  //
+  it("moderator with LOCK_THREADS and PIN can lock and pin posts", async () => {
+    const { authority: moderatorAuthority, profile: moderatorPda } = await createTestProfile(
+      "thread_moderator",
+    );
+    const [moderatorRolePda] = deriveGroupRoleAddress(groupPda, moderatorPda);
+
+    await program.methods
+      .assignRole(GroupPermission.LOCK_THREADS | GroupPermission.PIN)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+        assignee: moderatorPda,
+        assigneeRole: moderatorRolePda,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    const [threadPda] = derivePostAddress(groupPda, group.postCount);
+
+    await program.methods
+      .createPost("a heated thread", [], { primaryAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        post: threadPda,
+        group: groupPda,
+        parentGroup: null,
+        membership: null,
+        ban: deriveGroupBanAddress(groupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .rpc();
+
+    const moderatorAccounts = {
+      config: configPda,
+      authority: moderatorAuthority.publicKey,
+      profile: moderatorPda,
+      group: groupPda,
+      parentGroup: null,
+      post: threadPda,
+      role: moderatorRolePda,
+    };
+
+    await program.methods
+      .lockPost(true)
+      .accounts(moderatorAccounts)
+      .signers([moderatorAuthority])
+      .rpc();
+    await program.methods
+      .pinPost(true)
+      .accounts(moderatorAccounts)
+      .signers([moderatorAuthority])
+      .rpc();
+
+    const thread = await program.account.post.fetch(threadPda);
+    assert(thread.locked, "Expected the post to be locked");
+    assert(thread.pinned, "Expected the post to be pinned");
+
+    // locked posts no longer accept replies, even from the group's authority
+    await expect(
+      program.methods
+        .createReply("too late", [], 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          parentPost: threadPda,
+          reply: derivePostAddress(threadPda, thread.replyCount)[0],
+          group: groupPda,
+          parentGroup: null,
+          parentAuthor: profilePda,
+          engagement: null,
+          ban: deriveGroupBanAddress(groupPda, profilePda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: PostLocked");
+
+    // moderators without the permission can not lock posts
+    const { authority: hiderAuthority, profile: hiderPda } = await createTestProfile("post_hider");
+    const [hiderRolePda] = deriveGroupRoleAddress(groupPda, hiderPda);
+
+    await program.methods
+      .assignRole(GroupPermission.HIDE_POSTS)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+        assignee: hiderPda,
+        assigneeRole: hiderRolePda,
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .lockPost(false)
+        .accounts({
+          ...moderatorAccounts,
+          authority: hiderAuthority.publicKey,
+          profile: hiderPda,
+          role: hiderRolePda,
+        })
+        .signers([hiderAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+});
+
+describe("group bans", () => {
+  //
+  it("banned profiles cannot reply", async () => {
+    const { authority: spammerAuthority, profile: spammerPda } = await createTestProfile("spammer");
+    const [banPda] = deriveGroupBanAddress(postGroupPda, spammerPda);
+
+    await program.methods
+      .banFromGroup(null)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        role: null,
+        bannedProfile: spammerPda,
+        ban: banPda,
+      })
+      .rpc();
+
+    const ban = await program.account.groupBan.fetch(banPda);
+    assert(ban.expiresSlot === null, "Expected the ban to be permanent");
+
+    const post = await program.account.post.fetch(postPda);
+    const [spamReplyPda] = derivePostAddress(postPda, post.replyCount);
+    const [engagementPda] = deriveEngagementAddress(spammerPda, profilePda);
+
+    const createSpamReply = () =>
+      program.methods
+        .createReply("spam", [], 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: spammerAuthority.publicKey,
+          author: spammerPda,
+          parentPost: postPda,
+          reply: spamReplyPda,
+          group: postGroupPda,
+          parentGroup: null,
+          parentAuthor: profilePda,
+          engagement: engagementPda,
+          ban: banPda,
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .signers([spammerAuthority])
+        .rpc();
+
+    await expect(createSpamReply()).to.eventually.be.rejectedWith("Error Code: Banned");
+
+    // lifting the ban allows the profile to reply again
+    await program.methods
+      .unbanFromGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        role: null,
+        ban: banPda,
+      })
+      .rpc();
+
+    await createSpamReply();
+
+    const reply = await program.account.post.fetch(spamReplyPda);
+    assert(
+      reply.author.toBase58() === spammerPda.toBase58(),
+      "Expected 'author' to be the spammer",
+    );
+  });
+});
+
+describe("token gated post groups", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "holders_only";
+
+  //
+  it("only token holders can post", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(
+        postGroupInput(random_seed_group, groupName, { membershipMode: { open: {} } }),
+      )
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const mint = await createMint(provider.connection, payer, payer.publicKey, null, 0);
+
+    await program.methods
+      .setPostGroupTokenGate({ mint, minAmount: new anchor.BN(10) })
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(group.tokenGate.mint.toBase58() === mint.toBase58(), "Expected the token gate mint");
+
+    const { authority: holderAuthority, profile: holderPda } = await createTestProfile("holder");
+    const tokenAccount = await createAccount(
+      provider.connection,
+      payer,
+      mint,
+      holderAuthority.publicKey,
+    );
+    const [holderPostPda] = derivePostAddress(groupPda, 0);
+
+    const createHolderPost = (gateTokenAccount: anchor.web3.PublicKey | null) =>
+      program.methods
+        .createPost("holder post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: holderAuthority.publicKey,
+          author: holderPda,
+          post: holderPostPda,
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, holderPda)[0],
+          parentBan: null,
+          gateTokenAccount,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .signers([holderAuthority])
+        .rpc();
+
+    await expect(createHolderPost(null)).to.eventually.be.rejectedWith(
+      "Error Code: MissingTokenAccount",
+    );
+
+    // holding less than the minimum amount is not enough
+    await mintTo(provider.connection, payer, mint, tokenAccount, payer, 5);
+    await expect(createHolderPost(tokenAccount)).to.eventually.be.rejectedWith(
+      "Error Code: InsufficientTokenBalance",
+    );
+
+    await mintTo(provider.connection, payer, mint, tokenAccount, payer, 5);
+    await createHolderPost(tokenAccount);
+
+    const post = await program.account.post.fetch(holderPostPda);
+    assert(post.author.toBase58() === holderPda.toBase58(), "Expected 'author' to be the holder");
+  });
+
+  //
+  it("set post group token gate: incorrect authority", async () => {
+    const { authority: otherAuthority, profile: otherPda } = await createTestProfile("not_owner");
+
+    await expect(
+      program.methods
+        .setPostGroupTokenGate(null)
+        .accounts({
+          config: configPda,
+          authority: otherAuthority.publicKey,
+          profile: otherPda,
+          group: groupPda,
+          parentGroup: null,
+          role: null,
+        })
+        .signers([otherAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+});
+
+describe("collection gated post groups", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "collectors_only";
+
+  //
+  it("posting requires an NFT from the collection", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(
+        postGroupInput(random_seed_group, groupName, { membershipMode: { open: {} } }),
+      )
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    // the collection is identified by the mint of its own (collection) NFT
+    const { mint: collection } = await createNft(provider, payer, payer.publicKey);
+
+    await program.methods
+      .setPostGroupCollectionGate(collection)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(
+      group.collectionGate.toBase58() === collection.toBase58(),
+      "Expected the collection gate to be set",
+    );
+
+    const { authority: collectorAuthority, profile: collectorPda } = await createTestProfile(
+      "collector",
+    );
+    const [collectorPostPda] = derivePostAddress(groupPda, 0);
+
+    const createCollectorPost = (
+      nftTokenAccount: anchor.web3.PublicKey | null,
+      nftMetadata: anchor.web3.PublicKey | null = null,
+    ) =>
+      program.methods
+        .createPost("collector post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: collectorAuthority.publicKey,
+          author: collectorPda,
+          post: collectorPostPda,
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, collectorPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount,
+          nftMetadata,
+          activity: null,
+          bond: null,
+        })
+        .signers([collectorAuthority])
+        .rpc();
+
+    await expect(createCollectorPost(null)).to.eventually.be.rejectedWith(
+      "Error Code: MissingTokenAccount",
+    );
+
+    const nft = await createNft(provider, payer, collectorAuthority.publicKey, collection);
+
+    // holding a token without its collection metadata is not enough
+    await expect(createCollectorPost(nft.tokenAccount)).to.eventually.be.rejectedWith(
+      "Error Code: MissingMetadataAccount",
+    );
+
+    // only the collection's update authority can verify an NFT is part of it
+    await expect(createCollectorPost(nft.tokenAccount, nft.metadata)).to.eventually.be.rejectedWith(
+      "Error Code: NotInCollection",
+    );
+
+    await verifyCollection(provider, payer, nft.mint, collection);
+    await createCollectorPost(nft.tokenAccount, nft.metadata);
+
+    const post = await program.account.post.fetch(collectorPostPda);
+    assert(
+      post.author.toBase58() === collectorPda.toBase58(),
+      "Expected 'author' to be the collector",
+    );
+  });
+
+  //
+  it("create post: NFT from the wrong collection", async () => {
+    const { authority: otherAuthority, profile: otherPda } = await createTestProfile(
+      "other_collector",
+    );
+
+    // an NFT verified as part of another collection
+    const { mint: otherCollection } = await createNft(provider, payer, payer.publicKey);
+    const nft = await createNft(provider, payer, otherAuthority.publicKey, otherCollection);
+    await verifyCollection(provider, payer, nft.mint, otherCollection);
+
+    const group = await program.account.postGroup.fetch(groupPda);
+
+    await expect(
+      program.methods
+        .createPost("other collector post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: otherAuthority.publicKey,
+          author: otherPda,
+          post: derivePostAddress(groupPda, group.postCount)[0],
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, otherPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: nft.tokenAccount,
+          nftMetadata: nft.metadata,
+          activity: null,
+          bond: null,
+        })
+        .signers([otherAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: NotInCollection");
+  });
+});
+
+describe("channels", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "community";
+
+  //
+  it("channels inherit the parent's memberships", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_group, groupName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const channelSeed = await deriveChannelSeed(groupPda, 0);
+    const [channelPda] = deriveChannelAddress(channelSeed);
+
+    await program.methods
+      .createChannel(
+        postGroupInput(channelSeed, "general", {
+          inheritMembership: true,
+          inheritModeration: true,
+        }),
+      )
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        parentGroup: groupPda,
+        channel: channelPda,
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    const channel = await program.account.postGroup.fetch(channelPda);
+    assert(group.channelCount === 1, "Expected the parent to count its channels");
+    assert(
+      channel.parentGroup.toBase58() === groupPda.toBase58(),
+      "Expected 'parentGroup' to be the 'groupPda'",
+    );
+
+    // members of the parent are allowed to post in the channel
+    const { authority: memberAuthority, profile: memberPda } = await createTestProfile(
+      "community_member",
+    );
+    const [membershipPda] = deriveMembershipAddress(groupPda, memberPda);
+
+    await program.methods
+      .addMember()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        member: memberPda,
+        membership: membershipPda,
+        role: null,
+      })
+      .rpc();
+
+    const [channelPostPda] = derivePostAddress(channelPda, 0);
+
+    await program.methods
+      .createPost("channel post", [], { primaryAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        authority: memberAuthority.publicKey,
+        author: memberPda,
+        post: channelPostPda,
+        group: channelPda,
+        parentGroup: groupPda,
+        membership: membershipPda,
+        ban: deriveGroupBanAddress(groupPda, memberPda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .signers([memberAuthority])
+      .rpc();
+
+    const post = await program.account.post.fetch(channelPostPda);
+    assert(post.group.toBase58() === channelPda.toBase58(), "Expected 'group' to be the channel");
+  });
+
+  //
+  it("create channel: invalid random seed", async () => {
+    // channels must use the parent's current `channelCount`
+    const channelSeed = await deriveChannelSeed(groupPda, 5);
+    const [channelPda] = deriveChannelAddress(channelSeed);
+
+    await expect(
+      program.methods
+        .createChannel(postGroupInput(channelSeed, "announcements"))
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          parentGroup: groupPda,
+          channel: channelPda,
+          role: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidChannel");
+  });
+
+  //
+  it("root groups can not occupy a channel's address", async () => {
+    const channelSeed = await deriveChannelSeed(groupPda, 1);
+    const squatterName = "channel_squatter";
+
+    // a root group created with the seed of the parent's next channel
+    await program.methods
+      .createPostGroup(postGroupInput(channelSeed, squatterName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: derivePostGroupAddress(channelSeed)[0],
+        lookupAccount: deriveLookupAccountAddress("post_group", squatterName)[0],
+      })
+      .rpc();
+
+    await program.methods
+      .createChannel(postGroupInput(channelSeed, "random"))
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        parentGroup: groupPda,
+        channel: deriveChannelAddress(channelSeed)[0],
+        role: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(group.channelCount === 2, "Expected the channel to still be created");
+  });
+
+  //
+  it("create post: missing parent group", async () => {
+    const [channelPda] = deriveChannelAddress(await deriveChannelSeed(groupPda, 0));
+    const [channelPostPda] = derivePostAddress(channelPda, 1);
+
+    await expect(
+      program.methods
+        .createPost("channel post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          post: channelPostPda,
+          group: channelPda,
+          // channels must always be used along with their parent
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, profilePda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: MissingParentGroup");
+  });
+
+  //
+  it("add member: channel inherits the parent's memberships", async () => {
+    const [channelPda] = deriveChannelAddress(await deriveChannelSeed(groupPda, 0));
+    const { profile: memberPda } = await createTestProfile("channel_member");
+
+    // the membership would never be read, since the parent's memberships apply instead
+    await expect(
+      program.methods
+        .addMember()
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: channelPda,
+          parentGroup: groupPda,
+          member: memberPda,
+          membership: deriveMembershipAddress(channelPda, memberPda)[0],
+          role: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InheritedFromParent");
+  });
+
+  //
+  it("update post group: channels can be renamed and stop inheriting", async () => {
+    const channelSeed = await deriveChannelSeed(groupPda, 0);
+    const [channelPda] = deriveChannelAddress(channelSeed);
+
+    await program.methods
+      .updatePostGroup(
+        postGroupInput(channelSeed, "lounge", {
+          inheritMembership: false,
+          inheritModeration: true,
+        }),
+      )
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: channelPda,
+        parentGroup: groupPda,
+        role: null,
+      })
+      .rpc();
+
+    const channel = await program.account.postGroup.fetch(channelPda);
+    assert(channel.name === "lounge", "Expected 'name' to update");
+    assert(!channel.inheritMembership, "Expected 'inheritMembership' to update");
+    assert(channel.inheritModeration, "Expected 'inheritModeration' to be kept");
+
+    // restore the inherited memberships for the remaining tests
+    await program.methods
+      .updatePostGroup(
+        postGroupInput(channelSeed, "general", {
+          inheritMembership: true,
+          inheritModeration: true,
+        }),
+      )
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: channelPda,
+        parentGroup: groupPda,
+        role: null,
+      })
+      .rpc();
+  });
+
+  //
+  it("create post: parent bans apply to channels with their own memberships", async () => {
+    const channelSeed = await deriveChannelSeed(groupPda, 0);
+    const [channelPda] = deriveChannelAddress(channelSeed);
+    const { authority: outcastAuthority, profile: outcastPda } = await createTestProfile(
+      "channel_outcast",
+    );
+    const [parentBanPda] = deriveGroupBanAddress(groupPda, outcastPda);
+
+    await program.methods
+      .banFromGroup(null)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+        bannedProfile: outcastPda,
+        ban: parentBanPda,
+      })
+      .rpc();
+
+    // the channel manages its own (open) memberships
+    const updateChannel = (overrides: Parameters<typeof postGroupInput>[2]) =>
+      program.methods
+        .updatePostGroup(postGroupInput(channelSeed, "general", overrides))
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: channelPda,
+          parentGroup: groupPda,
+          role: null,
+        })
+        .rpc();
+
+    await updateChannel({
+      membershipMode: { open: {} },
+      inheritMembership: false,
+      inheritModeration: true,
+    });
+
+    const channel = await program.account.postGroup.fetch(channelPda);
+    const createChannelPost = (parentBan: anchor.web3.PublicKey | null) =>
+      program.methods
+        .createPost("outcast post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: outcastAuthority.publicKey,
+          author: outcastPda,
+          post: derivePostAddress(channelPda, channel.postCount)[0],
+          group: channelPda,
+          parentGroup: groupPda,
+          membership: null,
+          ban: deriveGroupBanAddress(channelPda, outcastPda)[0],
+          parentBan,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .signers([outcastAuthority])
+        .rpc();
+
+    // the parent's ban can not be bypassed by omitting it
+    await expect(createChannelPost(null)).to.eventually.be.rejectedWith(
+      "Error Code: MissingParentBan",
+    );
+    await expect(createChannelPost(parentBanPda)).to.eventually.be.rejectedWith(
+      "Error Code: Banned",
+    );
+
+    // restore the inherited memberships for the remaining tests
+    await updateChannel({ inheritMembership: true, inheritModeration: true });
+  });
+
+  //
+  it("channels are transferred along with their parent", async () => {
+    const [channelPda] = deriveChannelAddress(await deriveChannelSeed(groupPda, 0));
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+    const { authority: successorAuthority, profile: successorPda } = await createTestProfile(
+      "channel_successor",
+    );
+
+    // channels can not be transferred on their own
+    await expect(
+      program.methods
+        .transferPostGroup()
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: channelPda,
+          newAuthority: successorPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidChannel");
+
+    await program.methods
+      .transferPostGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        newAuthority: successorPda,
+      })
+      .rpc();
+
+    await program.methods
+      .acceptPostGroupTransfer()
+      .accounts({
+        config: configPda,
+        authority: successorAuthority.publicKey,
+        profile: successorPda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .signers([successorAuthority])
+      .rpc();
+
+    // the previous owner no longer controls the channel
+    await expect(
+      program.methods
+        .setPostGroupArchived(true)
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: channelPda,
+          parentGroup: groupPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+
+    await program.methods
+      .setPostGroupArchived(true)
+      .accounts({
+        config: configPda,
+        authority: successorAuthority.publicKey,
+        profile: successorPda,
+        group: channelPda,
+        parentGroup: groupPda,
+      })
+      .signers([successorAuthority])
+      .rpc();
+
+    const channel = await program.account.postGroup.fetch(channelPda);
+    assert(channel.archived, "Expected the new owner to be able to archive the channel");
+    assert(
+      channel.authority.toBase58() === successorPda.toBase58(),
+      "Expected the channel 'authority' to follow its parent",
+    );
+  });
+});
+
+describe("archived post groups", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "dead_community";
+  const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+  const createGroupPost = (postId: number) =>
+    program.methods
+      .createPost("last words", [], { primaryAuthor: {} }, 0)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        post: derivePostAddress(groupPda, postId)[0],
+        group: groupPda,
+        parentGroup: null,
+        membership: null,
+        ban: deriveGroupBanAddress(groupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .rpc();
+
+  //
+  it("archived groups are read-only", async () => {
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_group, groupName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    await createGroupPost(0);
+
+    await program.methods
+      .setPostGroupArchived(true)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+      })
+      .rpc();
+
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(group.archived, "Expected the group to be archived");
+
+    await expect(createGroupPost(1)).to.eventually.be.rejectedWith("Error Code: GroupArchived");
+
+    // existing posts are still readable
+    const post = await program.account.post.fetch(derivePostAddress(groupPda, 0)[0]);
+    assert(post.metadataUri === "last words", "Expected 'metadataUri' to match");
+  });
+
+  //
+  it("close post group: not empty", async () => {
+    // groups with posts must be archived before they can be closed
+    await expect(
+      program.methods
+        .closePostGroup()
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: postGroupPda,
+          parentGroup: null,
+          lookupAccount: deriveLookupAccountAddress("post_group", "renamed")[0],
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: GroupNotEmpty");
+  });
+
+  //
+  it("close post group: archived groups release their name", async () => {
+    await program.methods
+      .closePostGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    await expect(program.account.lookupAccount.fetch(lookupAccountPda)).to.eventually.be.rejectedWith(
+      "Account does not exist or has no data",
+    );
+
+    // the group remains as a tombstone, since its posts still reference it
+    const group = await program.account.postGroup.fetch(groupPda);
+    assert(group.name === "", "Expected 'name' to be released");
+    assert(group.archived, "Expected the group to remain archived");
+
+    // the tombstone can never accept posts again
+    await expect(
+      program.methods
+        .setPostGroupArchived(false)
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: groupPda,
+          parentGroup: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: GroupClosed");
+
+    // the released name is available to anyone
+    const random_seed_successor = anchor.web3.Keypair.generate().publicKey.toBytes();
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_successor, groupName))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: derivePostGroupAddress(random_seed_successor)[0],
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+  });
+
+  //
+  it("close post group", async () => {
+    const random_seed_empty = anchor.web3.Keypair.generate().publicKey.toBytes();
+    const [emptyGroupPda] = derivePostGroupAddress(random_seed_empty);
+    const [emptyLookupAccountPda] = deriveLookupAccountAddress("post_group", "empty_community");
+
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_empty, "empty_community"))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: emptyGroupPda,
+        lookupAccount: emptyLookupAccountPda,
+      })
+      .rpc();
+
+    await program.methods
+      .closePostGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: emptyGroupPda,
+        parentGroup: null,
+        lookupAccount: emptyLookupAccountPda,
+      })
+      .rpc();
+
+    await expect(program.account.postGroup.fetch(emptyGroupPda)).to.eventually.be.rejectedWith(
+      "Account does not exist or has no data",
+    );
+    await expect(
+      program.account.lookupAccount.fetch(emptyLookupAccountPda),
+    ).to.eventually.be.rejectedWith("Account does not exist or has no data");
+  });
+
+  //
+  it("create post group: empty name, which denotes a closed group", async () => {
+    const random_seed_unnamed = anchor.web3.Keypair.generate().publicKey.toBytes();
+
+    await expect(
+      program.methods
+        .createPostGroup(postGroupInput(random_seed_unnamed, ""))
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          group: derivePostGroupAddress(random_seed_unnamed)[0],
+          lookupAccount: deriveLookupAccountAddress("post_group", "")[0],
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: EmptyName");
+  });
+});
+
+describe("reports", () => {
+  //
+  it("report post and resolve the report", async () => {
+    const { authority: reporterAuthority, profile: reporterPda } = await createTestProfile(
+      "reporter",
+    );
+    const post = await program.account.post.fetch(postPda);
+    const [reportPda] = deriveReportAddress(postPda, post.reportCount);
+
+    await program.methods
+      .reportPost(0, "https://example.com/evidence.png")
+      .accounts({
+        config: configPda,
+        authority: reporterAuthority.publicKey,
+        reporter: reporterPda,
+        post: postPda,
+        report: reportPda,
+      })
+      .signers([reporterAuthority])
+      .rpc();
+
+    const updatedPost = await program.account.post.fetch(postPda);
+    assert(updatedPost.reportCount === post.reportCount + 1, "Expected 'reportCount' to increment");
+
+    // only the group's moderators can resolve reports
+    await expect(
+      program.methods
+        .resolveReport({ dismissed: {} })
+        .accounts({
+          config: configPda,
+          authority: reporterAuthority.publicKey,
+          profile: reporterPda,
+          group: postGroupPda,
+          parentGroup: null,
+          post: postPda,
+          report: reportPda,
+          role: null,
+        })
+        .signers([reporterAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+
+    const resolveReport = () =>
+      program.methods
+        .resolveReport({ upheld: {} })
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: postGroupPda,
+          parentGroup: null,
+          post: postPda,
+          report: reportPda,
+          role: null,
+        })
+        .rpc();
+
+    await resolveReport();
+
+    const report = await program.account.report.fetch(reportPda);
+    assert("upheld" in report.outcome, "Expected the report to be upheld");
+    assert(
+      report.resolvedBy.toBase58() === profilePda.toBase58(),
+      "Expected 'resolvedBy' to be the 'profilePda'",
+    );
+
+    await expect(resolveReport()).to.eventually.be.rejectedWith(
+      "Error Code: ReportAlreadyResolved",
+    );
+  });
+});
+
+describe("content labels", () => {
+  //
+  it("moderator labels override the author's labels", async () => {
+    const group = await program.account.postGroup.fetch(postGroupPda);
+    const [labeledPostPda] = derivePostAddress(postGroupPda, group.postCount);
+
+    await program.methods
+      .createPost("labeled post", [], { primaryAuthor: {} }, PostLabel.SPOILER)
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        post: labeledPostPda,
+        group: postGroupPda,
+        parentGroup: null,
+        membership: null,
+        ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+        parentBan: null,
+        gateTokenAccount: null,
+        nftTokenAccount: null,
+        nftMetadata: null,
+        activity: null,
+        bond: null,
+      })
+      .rpc();
+
+    // the group authority adds a label the author left off
+    await program.methods
+      .labelPost(PostLabel.SPOILER | PostLabel.NSFW)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        post: labeledPostPda,
+        role: null,
+      })
+      .rpc();
+
+    // the author removing its own labels does not remove the moderator's labels
+    await program.methods
+      .updatePost("labeled post", 0)
+      .accounts({
+        config: configPda,
+        author: profilePda,
+        post: labeledPostPda,
+        group: postGroupPda,
+      })
+      .rpc();
+
+    const post = await program.account.post.fetch(labeledPostPda);
+    assert(post.labels === 0, "Expected the author's 'labels' to update");
+    assert(
+      post.moderatorLabels === (PostLabel.SPOILER | PostLabel.NSFW),
+      "Expected the 'moderatorLabels' to be preserved",
+    );
+  });
+
+  //
+  it("create post: invalid labels", async () => {
+    const group = await program.account.postGroup.fetch(postGroupPda);
+
+    await expect(
+      program.methods
+        .createPost("labeled post", [], { primaryAuthor: {} }, 1 << 7)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          post: derivePostAddress(postGroupPda, group.postCount)[0],
+          group: postGroupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(postGroupPda, profilePda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidLabels");
+  });
+});
+
+describe("post moderation", () => {
+  //
+  it("moderators can hide and restore posts", async () => {
+    await program.methods
+      .moderatePost({ hidden: {} }, 0)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        post: postPda,
+        role: null,
+      })
+      .rpc();
+
+    let post = await program.account.post.fetch(postPda);
+    assert("hidden" in post.moderationStatus, "Expected the post to be hidden");
+    assert(post.metadataUri === "winning metadataUri", "Expected the author's data to be intact");
+
+    await program.methods
+      .moderatePost({ visible: {} }, 0)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: postGroupPda,
+        parentGroup: null,
+        post: postPda,
+        role: null,
+      })
+      .rpc();
+
+    post = await program.account.post.fetch(postPda);
+    assert("visible" in post.moderationStatus, "Expected the post to be visible again");
+  });
+
+  //
+  it("moderate post: not a moderator", async () => {
+    const { authority: otherAuthority, profile: otherPda } = await createTestProfile("not_a_mod");
+
+    await expect(
+      program.methods
+        .moderatePost({ removed: {} }, 0)
+        .accounts({
+          config: configPda,
+          authority: otherAuthority.publicKey,
+          profile: otherPda,
+          group: postGroupPda,
+          parentGroup: null,
+          post: postPda,
+          role: null,
+        })
+        .signers([otherAuthority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+});
+
+describe("rate limits", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "slow_mode";
+
+  //
+  it("profiles posting too frequently are rate limited", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(
+        postGroupInput(random_seed_group, groupName, {
+          membershipMode: { open: {} },
+          rateLimit: { minIntervalSlots: new anchor.BN(1_000_000), burst: 1 },
+        }),
+      )
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    const { authority: posterAuthority, profile: posterPda } = await createTestProfile("poster");
+    const [activityPda] = derivePostActivityAddress(groupPda, posterPda);
+
+    const createPosterPost = (postId: number) =>
+      program.methods
+        .createPost("rapid fire", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: posterAuthority.publicKey,
+          author: posterPda,
+          post: derivePostAddress(groupPda, postId)[0],
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, posterPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: activityPda,
+          bond: null,
+        })
+        .signers([posterAuthority])
+        .rpc();
+
+    await createPosterPost(0);
+
+    const activity = await program.account.postActivity.fetch(activityPda);
+    assert(activity.tokens === 0, "Expected the burst allowance to be used up");
+
+    await expect(createPosterPost(1)).to.eventually.be.rejectedWith("Error Code: RateLimited");
+  });
+
+  //
+  it("create post group: invalid rate limit", async () => {
+    const createRateLimitedGroup = (minIntervalSlots: number, burst: number) => {
+      const randomSeed = anchor.web3.Keypair.generate().publicKey.toBytes();
+      const name = "invalid_slow_mode";
+
+      return program.methods
+        .createPostGroup(
+          postGroupInput(randomSeed, name, {
+            rateLimit: { minIntervalSlots: new anchor.BN(minIntervalSlots), burst },
+          }),
+        )
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          author: profilePda,
+          group: derivePostGroupAddress(randomSeed)[0],
+          lookupAccount: deriveLookupAccountAddress("post_group", name)[0],
+        })
+        .rpc();
+    };
+
+    // a burst of zero never allows a single post
+    await expect(createRateLimitedGroup(10, 0)).to.eventually.be.rejectedWith(
+      "Error Code: InvalidRateLimit",
+    );
+
+    // an interval of zero can not be used to refill the allowance
+    await expect(createRateLimitedGroup(0, 1)).to.eventually.be.rejectedWith(
+      "Error Code: InvalidRateLimit",
+    );
+  });
+});
+
+describe("posting bonds", () => {
+  const random_seed_group = anchor.web3.Keypair.generate().publicKey.toBytes();
+  const [groupPda] = derivePostGroupAddress(random_seed_group);
+  const groupName = "bonded";
+  const bondAmount = anchor.web3.LAMPORTS_PER_SOL / 10;
+
+  //
+  it("posting requires a bond that moderators can slash", async () => {
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", groupName);
+
+    await program.methods
+      .createPostGroup(
+        postGroupInput(random_seed_group, groupName, { membershipMode: { open: {} } }),
+      )
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: groupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    // a bond of zero lamports would never be slashable
+    await expect(
+      program.methods
+        .setPostGroupBond({
+          amount: new anchor.BN(0),
+          cooldownSlots: new anchor.BN(0),
+        })
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: groupPda,
+          parentGroup: null,
+          role: null,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidBondAmount");
+
+    await program.methods
+      .setPostGroupBond({
+        amount: new anchor.BN(bondAmount),
+        cooldownSlots: new anchor.BN(0),
+      })
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        role: null,
+      })
+      .rpc();
+
+    const { authority: posterAuthority, profile: posterPda } = await createTestProfile(
+      "bonded_poster",
+    );
+    const [bondPda] = deriveBondAddress(groupPda, posterPda);
+
+    // fund the poster's authority so it can deposit the bond
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: payer.publicKey,
+          toPubkey: posterAuthority.publicKey,
+          lamports: anchor.web3.LAMPORTS_PER_SOL,
+        }),
+      ),
+    );
+
+    const createPosterPost = async (bond: anchor.web3.PublicKey | null) => {
+      const group = await program.account.postGroup.fetch(groupPda);
+
+      return program.methods
+        .createPost("bonded post", [], { primaryAuthor: {} }, 0)
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: posterAuthority.publicKey,
+          author: posterPda,
+          post: derivePostAddress(groupPda, group.postCount)[0],
+          group: groupPda,
+          parentGroup: null,
+          membership: null,
+          ban: deriveGroupBanAddress(groupPda, posterPda)[0],
+          parentBan: null,
+          gateTokenAccount: null,
+          nftTokenAccount: null,
+          nftMetadata: null,
+          activity: null,
+          bond,
+        })
+        .signers([posterAuthority])
+        .rpc();
+    };
+
+    await expect(createPosterPost(null)).to.eventually.be.rejectedWith("Error Code: MissingBond");
+
+    await program.methods
+      .depositBond()
+      .accounts({
+        config: configPda,
+        authority: posterAuthority.publicKey,
+        profile: posterPda,
+        group: groupPda,
+        bond: bondPda,
+      })
+      .signers([posterAuthority])
+      .rpc();
+
+    await createPosterPost(bondPda);
+    const [spamPostPda] = derivePostAddress(groupPda, 0);
+    const [groupTreasuryPda] = deriveGroupTreasuryAddress(groupPda);
+
+    const slashBond = () =>
+      program.methods
+        .slashBond()
+        .accounts({
+          config: configPda,
+          profile: profilePda,
+          group: groupPda,
+          parentGroup: null,
+          bond: bondPda,
+          post: spamPostPda,
+          groupTreasury: groupTreasuryPda,
+          role: null,
+        })
+        .rpc();
+
+    // bonds can only be slashed for removed posts
+    await expect(slashBond()).to.eventually.be.rejectedWith("Error Code: PostNotRemoved");
+
+    // the group authority removes the post and slashes the bond after finding it is spam
+    await program.methods
+      .moderatePost({ removed: {} }, 0)
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: groupPda,
+        parentGroup: null,
+        post: spamPostPda,
+        role: null,
+      })
+      .rpc();
+
+    const treasuryBalance = await provider.connection.getBalance(treasuryPda);
+    await slashBond();
+
+    const post = await program.account.post.fetch(spamPostPda);
+    assert(post.bondSlashed, "Expected the post to be marked as slashed");
+
+    const bond = await program.account.bond.fetch(bondPda);
+    assert(bond.amount.eqn(0), "Expected the bond to be slashed");
+
+    const groupTreasury = await program.account.groupTreasury.fetch(groupTreasuryPda);
+    assert(groupTreasury.group.equals(groupPda), "Expected the treasury to belong to the group");
+    assert(groupTreasury.totalCollected.eqn(bondAmount), "Expected the slash to be recorded");
+    assert(
+      (await provider.connection.getBalance(treasuryPda)) === treasuryBalance,
+      "Expected the program's treasury to not receive the slashed bond",
+    );
+
+    const withdrawGroupTreasury = (
+      authority: anchor.web3.Keypair,
+      profile: anchor.web3.PublicKey,
+      amount: number,
+    ) =>
+      program.methods
+        .withdrawGroupTreasury(new anchor.BN(amount))
+        .accounts({
+          config: configPda,
+          authority: authority.publicKey,
+          profile,
+          group: groupPda,
+          parentGroup: null,
+          groupTreasury: groupTreasuryPda,
+          destination: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    // only the group's authority can withdraw the slashed bonds
+    await expect(
+      withdrawGroupTreasury(posterAuthority, posterPda, bondAmount),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+    await expect(
+      withdrawGroupTreasury(payer, profilePda, bondAmount + 1),
+    ).to.eventually.be.rejectedWith("Error Code: InsufficientTreasuryBalance");
+
+    const groupTreasuryBalance = await provider.connection.getBalance(groupTreasuryPda);
+    await withdrawGroupTreasury(payer, profilePda, bondAmount);
+    assert(
+      (await provider.connection.getBalance(groupTreasuryPda)) ===
+        groupTreasuryBalance - bondAmount,
+      "Expected the slashed bond to be withdrawn",
+    );
+
+    await expect(createPosterPost(bondPda)).to.eventually.be.rejectedWith(
+      "Error Code: InsufficientBond",
+    );
+
+    // each removed post can only be used to slash the bond once
+    await expect(slashBond()).to.eventually.be.rejectedWith("Error Code: BondAlreadySlashed");
+
+    // the group has no cooldown, so the bond's rent can be reclaimed right away
+    await program.methods
+      .requestBondWithdrawal()
+      .accounts({
+        config: configPda,
+        authority: posterAuthority.publicKey,
+        profile: posterPda,
+        group: groupPda,
+        bond: bondPda,
+      })
+      .signers([posterAuthority])
+      .rpc();
+
+    await program.methods
+      .withdrawBond()
+      .accounts({
+        config: configPda,
+        authority: posterAuthority.publicKey,
+        profile: posterPda,
+        bond: bondPda,
+      })
+      .signers([posterAuthority])
+      .rpc();
+
+    await expect(program.account.bond.fetch(bondPda)).to.eventually.be.rejectedWith(
+      "Account does not exist or has no data",
+    );
+  });
+
+  //
+  it("bonds can be withdrawn without a cooldown once the group is closed", async () => {
+    const random_seed_closed = anchor.web3.Keypair.generate().publicKey.toBytes();
+    const [closedGroupPda] = derivePostGroupAddress(random_seed_closed);
+    const [lookupAccountPda] = deriveLookupAccountAddress("post_group", "bonded_closed");
+
+    await program.methods
+      .createPostGroup(postGroupInput(random_seed_closed, "bonded_closed"))
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        author: profilePda,
+        group: closedGroupPda,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    await program.methods
+      .setPostGroupBond({
+        amount: new anchor.BN(bondAmount),
+        // a cooldown far longer than this test
+        cooldownSlots: new anchor.BN(1_000_000),
+      })
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: closedGroupPda,
+        parentGroup: null,
+        role: null,
+      })
+      .rpc();
+
+    const { authority, profile } = await createTestProfile("closed_bond_poster");
+    const [bondPda] = deriveBondAddress(closedGroupPda, profile);
+
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: payer.publicKey,
+          toPubkey: authority.publicKey,
+          lamports: anchor.web3.LAMPORTS_PER_SOL,
+        }),
+      ),
+    );
+
+    await program.methods
+      .depositBond()
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        profile,
+        group: closedGroupPda,
+        bond: bondPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .closePostGroup()
+      .accounts({
+        config: configPda,
+        profile: profilePda,
+        group: closedGroupPda,
+        parentGroup: null,
+        lookupAccount: lookupAccountPda,
+      })
+      .rpc();
+
+    await program.methods
+      .requestBondWithdrawal()
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        profile,
+        group: closedGroupPda,
+        bond: bondPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .withdrawBond()
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        profile,
+        bond: bondPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    await expect(program.account.bond.fetch(bondPda)).to.eventually.be.rejectedWith(
+      "Account does not exist or has no data",
+    );
+  });
+});
+
+describe("config", () => {
+  it("rejects writes while paused", async () => {
+    await program.methods
+      .setConfigPaused(true)
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+
+    await expect(createTestProfile("paused_profile")).to.eventually.be.rejectedWith(
+      "Error Code: ProgramPaused",
+    );
+
+    await program.methods
+      .setConfigPaused(false)
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+
+    await createTestProfile("paused_profile");
+  });
+
+  it("rejects updates from anyone but the admin", async () => {
+    const notAdmin = anchor.web3.Keypair.generate();
+
+    await expect(
+      program.methods
+        .setConfigPaused(true)
+        .accounts({
+          admin: notAdmin.publicKey,
+          config: configPda,
+        })
+        .signers([notAdmin])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: Unauthorized");
+  });
+
+  it("rejects limits above the program's hard limits", async () => {
+    await expect(
+      program.methods
+        .updateConfig(configInput({ maxTagsPerPost: 6 }))
+        .accounts({
+          admin: payer.publicKey,
+          config: configPda,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidConfig");
+  });
+});
+
+describe("protocol fees", () => {
+  const profileFee = 1_000_000;
+  const [feeExemptionPda] = deriveFeeExemptionAddress(payer.publicKey);
+
+  async function setFees(fees: Partial<typeof noFees>) {
+    await program.methods
+      .updateConfig(configInput({ fees: { ...noFees, ...fees } }))
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+  }
+
+  after(async () => {
+    await setFees(noFees);
+  });
+
+  it("charges fees into the treasury", async () => {
+    await setFees({ createProfile: new anchor.BN(profileFee) });
+
+    const balanceBefore = await provider.connection.getBalance(treasuryPda);
+    await expect(createTestProfile("fee_payer")).to.eventually.be.rejectedWith(
+      "Error Code: MissingTreasuryAccount",
+    );
+    await createTestProfile("fee_payer", null, treasuryPda);
+
+    assert(
+      (await provider.connection.getBalance(treasuryPda)) === balanceBefore + profileFee,
+      "Expected the treasury to receive the fee",
+    );
+
+    const treasury = await program.account.treasury.fetch(treasuryPda);
+    assert(treasury.totalCollected.gten(profileFee), "Expected the fee to be recorded");
+  });
+
+  it("charges more for short usernames", async () => {
+    // 1 to 3 character usernames are priced as premium names
+    const usernamePrices = [1_000_000_000, 500_000_000, 100_000_000, 10_000, 0];
+    await setFees({ usernamePrices: usernamePrices.map(price => new anchor.BN(price)) });
+
+    for (const username of ["q", "qq", "qqq", "qqqq", "a_long_username"]) {
+      const balanceBefore = await provider.connection.getBalance(treasuryPda);
+      await createTestProfile(username, null, treasuryPda);
+
+      const price = usernamePrices[Math.min(username.length, usernamePrices.length) - 1];
+      assert(
+        (await provider.connection.getBalance(treasuryPda)) === balanceBefore + price,
+        `Expected the "${username}" username to cost ${price} lamports`,
+      );
+    }
+  });
+
+  it("charges a fee for every vouch", async () => {
+    const vouchFee = 5_000_000;
+    await setFees({ vouch: new anchor.BN(vouchFee) });
+
+    const { profile: endorseePda } = await createTestProfile("paid_endorsee");
+    const vouchFor = (treasury: anchor.web3.PublicKey | null) =>
+      program.methods
+        .vouch(0)
+        .accounts({
+          config: configPda,
+          endorser: profilePda,
+          endorsee: endorseePda,
+          vouch: deriveVouchAddress(profilePda, endorseePda, 0)[0],
+          endorsement: deriveEndorsementAddress(profilePda, endorseePda)[0],
+          treasury,
+          feeExemption: null,
+        })
+        .rpc();
+
+    await expect(vouchFor(null)).to.eventually.be.rejectedWith(
+      "Error Code: MissingTreasuryAccount",
+    );
+
+    const balanceBefore = await provider.connection.getBalance(treasuryPda);
+    await vouchFor(treasuryPda);
+
+    assert(
+      (await provider.connection.getBalance(treasuryPda)) === balanceBefore + vouchFee,
+      "Expected the treasury to receive the vouch fee",
+    );
+  });
+
+  it("exempts allowlisted payers", async () => {
+    await setFees({ createProfile: new anchor.BN(profileFee) });
+
+    await program.methods
+      .addFeeExemption(payer.publicKey)
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+        feeExemption: feeExemptionPda,
+      })
+      .rpc();
+
+    const balanceBefore = await provider.connection.getBalance(treasuryPda);
+    await createTestProfile("fee_exempt", feeExemptionPda);
+
+    assert(
+      (await provider.connection.getBalance(treasuryPda)) === balanceBefore,
+      "Expected the exempt payer to not pay the fee",
+    );
+
+    await program.methods
+      .removeFeeExemption()
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+        feeExemption: feeExemptionPda,
+      })
+      .rpc();
+  });
+
+  it("lets the admin withdraw the fees", async () => {
+    const destination = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .withdrawTreasury(new anchor.BN(profileFee))
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+        treasury: treasuryPda,
+        destination,
+      })
+      .rpc();
+
+    assert(
+      (await provider.connection.getBalance(destination)) === profileFee,
+      "Expected the destination to receive the fees",
+    );
+
+    // the treasury must always remain rent exempt
+    const balance = await provider.connection.getBalance(treasuryPda);
+    await expect(
+      program.methods
+        .withdrawTreasury(new anchor.BN(balance))
+        .accounts({
+          admin: payer.publicKey,
+          config: configPda,
+          treasury: treasuryPda,
+          destination,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InsufficientTreasuryBalance");
+  });
+});
+
+describe("username leases", () => {
+  const username = "leased_name";
+  const [lookupAccountPda] = deriveLookupAccountAddress("profile", username);
+
+  before(async () => {
+    await program.methods
+      .updateConfig(
+        configInput({
+          usernameLeaseDuration: new anchor.BN(2),
+          usernameGracePeriod: new anchor.BN(2),
+        }),
+      )
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+  });
+
+  after(async () => {
+    await program.methods
+      .updateConfig(configInput())
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+  });
+
+  it("releases expired usernames so they can be registered again", async () => {
+    const { authority, profile, randomSeed } = await createTestProfile(username);
+
+    const lookupBefore = await program.account.lookupAccount.fetch(lookupAccountPda);
+    assert(lookupBefore.expiresAt !== null, "Expected the username to be leased");
+
+    const releaseUsername = () =>
+      program.methods
+        .releaseUsername()
+        .accounts({
+          config: configPda,
+          releaser: payer.publicKey,
+          profile,
+          lookupAccount: lookupAccountPda,
+        })
+        .rpc();
+
+    await expect(releaseUsername()).to.eventually.be.rejectedWith(
+      "Error Code: UsernameNotExpired",
+    );
+
+    await program.methods
+      .renewUsername()
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        authority: authority.publicKey,
+        profile,
+        lookupAccount: lookupAccountPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const lookupAfter = await program.account.lookupAccount.fetch(lookupAccountPda);
+    assert(lookupAfter.expiresAt.gt(lookupBefore.expiresAt), "Expected the lease to be extended");
+
+    // wait for the renewed lease and its grace period to end
+    await new Promise(resolve => setTimeout(resolve, 8000));
+    await releaseUsername();
+
+    const released = await program.account.profile.fetch(profile);
+    assert(released.username === "", "Expected the profile to no longer have a username");
+
+    // the profile keeps working without a username (e.g. sending back its current data)
+    await program.methods
+      .updateProfile({ ...released, name: "released" })
+      .accounts({
+        config: configPda,
+        authority: authority.publicKey,
+        profile,
+      })
+      .signers([authority])
+      .rpc();
+
+    // the released username is available to anyone
+    await createTestProfile(username);
+
+    // while the original profile can register a new username without an old lookup account
+    const [newLookupAccount] = deriveLookupAccountAddress("profile", "leased_fallback");
+    await program.methods
+      .changeUsername(randomSeed as unknown as number[], "leased_fallback")
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: deriveUsernameReservationAddress("leased_fallback")[0],
+        instructionsSysvar: null,
+        authority: authority.publicKey,
+        profile,
+        oldLookupAccount: null,
+        newLookupAccount,
+      })
+      .signers([authority])
+      .rpc();
+
+    const renamed = await program.account.profile.fetch(profile);
+    assert(renamed.username === "leased_fallback", "Expected the profile to have a new username");
+  });
+
+  it("rejects empty usernames, which denote a released username", async () => {
+    await expect(createTestProfile("")).to.eventually.be.rejectedWith("Error Code: EmptyUsername");
+  });
+
+  it("only migrates legacy lookup accounts", async () => {
+    // the lookup account was created with its current size, so there is nothing to migrate
+    const [lookupAccount] = deriveLookupAccountAddress("profile", "leased_fallback");
+    const { address } = await program.account.lookupAccount.fetch(lookupAccount);
+    await expect(
+      program.methods
+        .migrateLookupAccount()
+        .accounts({
+          config: configPda,
+          lookupAccount,
+          address,
+        })
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: InvalidAccount");
+  });
+
+  it("rejects renewals while leases are disabled", async () => {
+    const username = "disabled_lease";
+    const [lookupAccount] = deriveLookupAccountAddress("profile", username);
+    const { authority, profile } = await createTestProfile(username);
+
+    const lookupBefore = await program.account.lookupAccount.fetch(lookupAccount);
+    assert(lookupBefore.expiresAt !== null, "Expected the username to be leased");
+
+    // disable leases after the username was registered with one
+    await program.methods
+      .updateConfig(configInput())
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+
+    await expect(
+      program.methods
+        .renewUsername()
+        .accounts({
+          config: configPda,
+          treasury: null,
+          feeExemption: null,
+          authority: authority.publicKey,
+          profile,
+          lookupAccount,
+        })
+        .signers([authority])
+        .rpc(),
+    ).to.eventually.be.rejectedWith("Error Code: UsernameLeasesDisabled");
+
+    const lookupAfter = await program.account.lookupAccount.fetch(lookupAccount);
+    assert(lookupAfter.expiresAt.eq(lookupBefore.expiresAt), "Expected the lease to be kept");
+  });
+});
+
+describe("username reservations", () => {
+  const username = "reserved_brand";
+  const reservationKey = anchor.web3.Keypair.generate();
+  const [reservationPda] = deriveUsernameReservationAddress(username);
+
+  before(async () => {
+    await program.methods
+      .updateConfig(configInput({ reservationKey: reservationKey.publicKey }))
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+
+    await program.methods
+      .reserveUsername(username)
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+        reservation: reservationPda,
+      })
+      .rpc();
+  });
+
+  after(async () => {
+    await program.methods
+      .updateConfig(configInput())
+      .accounts({
+        admin: payer.publicKey,
+        config: configPda,
+      })
+      .rpc();
+  });
+
+  /**
+   * Claim the reserved username, with a voucher signed by the `voucherSigner` (if any)
+   * that is valid for `validSlots` more slots and was issued for the `voucherConfig`
+   */
+  async function claimUsername(
+    voucherSigner: anchor.web3.Keypair | null,
+    validSlots = 100,
+    voucherConfig = configPda,
+  ) {
+    const expiresSlot = (await provider.connection.getSlot()) + validSlots;
+    const authority = anchor.web3.Keypair.generate();
+    const randomSeed = anchor.web3.Keypair.generate().publicKey.toBytes();
+    const [profile] = deriveProfileAddress(randomSeed);
+    const [lookupAccount] = deriveLookupAccountAddress("profile", username);
+
+    await program.methods
+      .createProfile({
+        bump: 0, // this is ignored
+        randomSeed: randomSeed as unknown as number[],
+        authority: authority.publicKey,
+        name: username,
+        username,
+        imageUri: "",
+        metadataUri: "",
+        messagingKey: null,
+        endorsementCount: 0,
+        reputation: new anchor.BN(0),
+      })
+      .accounts({
+        config: configPda,
+        treasury: null,
+        feeExemption: null,
+        reservation: reservationPda,
+        instructionsSysvar: voucherSigner ? anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY : null,
+        authority: authority.publicKey,
+        profile,
+        lookupAccount,
+      })
+      .preInstructions(
+        voucherSigner
+          ? [
+              buildVoucherInstruction(
+                voucherSigner,
+                authority.publicKey,
+                expiresSlot,
+                username,
+                voucherConfig,
+              ),
+            ]
+          : [],
+      )
+      .signers([authority])
+      .rpc();
+
+    return profile;
+  }
+
+  it("rejects claims without a voucher", async () => {
+    await expect(claimUsername(null)).to.eventually.be.rejectedWith("Error Code: MissingVoucher");
+  });
+
+  it("rejects vouchers not signed by the reservation key", async () => {
+    await expect(claimUsername(anchor.web3.Keypair.generate())).to.eventually.be.rejectedWith(
+      "Error Code: InvalidVoucher",
+    );
+  });
+
+  it("rejects vouchers issued for another deployment's config", async () => {
+    const otherConfig = anchor.web3.Keypair.generate().publicKey;
+    await expect(claimUsername(reservationKey, 100, otherConfig)).to.eventually.be.rejectedWith(
+      "Error Code: InvalidVoucher",
+    );
+  });
+
+  it("rejects expired vouchers", async () => {
+    await expect(claimUsername(reservationKey, -1)).to.eventually.be.rejectedWith(
+      "Error Code: VoucherExpired",
+    );
+  });
+
+  it("lets the voucher's claimant register the username", async () => {
+    const profile = await claimUsername(reservationKey);
+
+    const claimed = await program.account.profile.fetch(profile);
+    assert(claimed.username === username, "Expected the reserved username to be claimed");
+  });
 });